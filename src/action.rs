@@ -1,18 +1,60 @@
 //! Defines Action-related functionality. This module includes the
 //! [`ActionSpawn`] trait and some Composite Actions for utility.
 
-use crate::thinker::Actor;
+use crate::{
+    scorer::{Scorer, ScorerCommands, ScorerSpawn},
+    thinker::Actor,
+};
 use bevy_ecs::{
     bundle::Bundle,
     component::Component,
     entity::{Entity, EntityMapper, MapEntities},
     query::QueryData,
     system::Commands,
+    world::{EntityRef, World},
 };
-use bevy_hierarchy::{AddChild, DespawnRecursive};
+use bevy_hierarchy::{AddChild, Children, DespawnRecursive, Parent};
+use bevy_log as log;
 use bevy_reflect::Reflect;
 use bevy_utils::all_tuples;
-use std::sync::Arc;
+use std::{
+    any::{Any, TypeId},
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Component types every Action has, whatever kind of Action it is.
+pub(crate) fn bookkeeping() -> [TypeId; 7] {
+    [
+        TypeId::of::<Actor>(),
+        TypeId::of::<ActionState>(),
+        TypeId::of::<Cancellation>(),
+        TypeId::of::<ActionOutcome>(),
+        TypeId::of::<ActionProgress>(),
+        TypeId::of::<Parent>(),
+        TypeId::of::<Children>(),
+    ]
+}
+
+/// Inserts the bundle built by `init` from `actor` into `entity`, unless
+/// either of them is gone by the time this runs.
+pub(crate) fn insert_from_actor<B: Bundle>(
+    world: &mut World,
+    actor: Entity,
+    entity: Entity,
+    init: impl FnOnce(EntityRef) -> B,
+) {
+    let Ok(actor) = world.get_entity(actor) else {
+        log::warn!("{:?} is gone, can't initialize {:?}", actor, entity);
+        return;
+    };
+    let bundle = init(actor);
+    if let Ok(mut entity) = world.get_entity_mut(entity) {
+        entity.insert(bundle);
+    }
+}
 
 #[derive(Debug, Clone, Copy, Reflect)]
 pub struct Action(pub(crate) Entity);
@@ -48,16 +90,73 @@ impl<'w, 's, 'a> ActionCommands<'w, 's, 'a> {
         Self { cmd, actor }
     }
 
+    /// Returns the actor entity the Action is being spawned for.
+    #[inline]
+    pub fn actor(&self) -> Entity {
+        self.actor.entity()
+    }
+
     #[inline]
     pub fn spawn(&mut self, bundle: impl Bundle) -> Action {
-        let bundle = (self.actor, ActionState::Executing, bundle);
+        let bundle = (
+            self.actor,
+            ActionState::Executing,
+            Cancellation::default(),
+            ActionOutcome::default(),
+            ActionProgress::default(),
+            bundle,
+        );
         Action(self.cmd.spawn(bundle).id())
     }
 
+    /// Inserts additional components into an already spawned Action.
     #[inline]
-    pub fn push_child(&mut self, Action(parent): Action, builder: &dyn ActionSpawn) {
+    pub fn insert(&mut self, Action(action): Action, bundle: impl Bundle) {
+        self.cmd.entity(action).insert(bundle);
+    }
+
+    /// Spawns an Action whose components are built from the actor's current
+    /// state, like its position or health when the Action starts. `init` gets
+    /// read-only access to the actor once the commands are applied, before
+    /// any Action system gets to see the Action.
+    #[inline]
+    pub fn spawn_with<B: Bundle>(
+        &mut self,
+        init: impl FnOnce(EntityRef) -> B + Send + 'static,
+    ) -> Action {
+        let action = self.spawn(());
+        self.insert_with(action, init);
+        action
+    }
+
+    /// Same as [`ActionCommands::insert`], but the components are built from
+    /// the actor's current state. See [`ActionCommands::spawn_with`].
+    #[inline]
+    pub fn insert_with<B: Bundle>(
+        &mut self,
+        Action(action): Action,
+        init: impl FnOnce(EntityRef) -> B + Send + 'static,
+    ) {
+        let actor = self.actor.entity();
+        self.cmd
+            .queue(move |world: &mut World| insert_from_actor(world, actor, action, init));
+    }
+
+    #[inline]
+    pub fn push_child(&mut self, Action(parent): Action, builder: &dyn ActionSpawn) -> Action {
         let Action(child) = builder.spawn(ActionCommands::new(self.cmd, self.actor));
-        self.cmd.queue(AddChild { parent, child })
+        self.cmd.queue(AddChild { parent, child });
+        Action(child)
+    }
+
+    /// Spawns a Scorer for the same actor as a child of `parent`. This is
+    /// useful for Composite Actions that look at [`Score`](crate::scorer::Score)s
+    /// while running.
+    #[inline]
+    pub fn push_scorer(&mut self, Action(parent): Action, builder: &dyn ScorerSpawn) -> Scorer {
+        let Scorer(child) = builder.spawn(ScorerCommands::new(self.cmd, self.actor));
+        self.cmd.queue(AddChild { parent, child });
+        Scorer(child)
     }
 }
 
@@ -82,7 +181,7 @@ pub enum ActionState {
     /// tick, **you must check whether the Cancelled state was set** and
     /// change do either Success or Failure. Thinkers will wait on Cancelled
     /// actions to do any necessary cleanup work, so this can hang your AI if
-    /// you don't look for it.
+    /// you don't look for it. See [`CancelReason`] for why it got cancelled.
     Cancelled,
 
     /// The Action was a success. This is used by Composite Actions to
@@ -142,15 +241,234 @@ impl ActionState {
     pub fn is_done(&self) -> bool {
         matches!(self, Self::Success | Self::Failure)
     }
+}
+
+/// Why an Action got [`ActionState::Cancelled`]. Action systems can read it
+/// with `cancel_reason()` on an [`ActionQuery`] item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum CancelReason {
+    /// The [`Thinker`](crate::thinker::Thinker) picked a different choice.
+    Preempted,
+    /// The [`Thinker`](crate::thinker::Thinker) has a
+    /// [scheduled](crate::thinker::Thinker::schedule) one-off Action to run.
+    Scheduled,
+    /// The parent Composite Action decided its result without this Action,
+    /// like a [`Sequence`](crate::sequence::Sequence) failing because another
+    /// Action failed.
+    ParentFinished,
+    /// The Scorer of a [`Guard`](crate::guard::Guard) dropped below its
+    /// threshold.
+    GuardDropped,
+    /// A [`StateMachine`](crate::state_machine::StateMachine) moved on to
+    /// another state.
+    Transition,
+    /// The Action ran out of time.
+    Timeout,
+    /// The Actor was despawned, or lost its
+    /// [`Thinker`](crate::thinker::Thinker). The Action only gets a single
+    /// frame to react before it is despawned.
+    ActorGone,
+}
+
+/// Holds the [`CancelReason`] of an [`ActionState::Cancelled`] Action.
+/// Cancelled Composite Actions pass their own reason on to their children.
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+pub struct Cancellation(Option<CancelReason>);
+
+impl Cancellation {
+    /// Returns why the Action got cancelled. `None` if it wasn't, or if it was
+    /// cancelled without a reason.
+    pub fn reason(&self) -> Option<CancelReason> {
+        self.0
+    }
+}
+
+/// [`ActionState`] with its [`Cancellation`], used by Composite Actions and
+/// [`Thinker`](crate::thinker::Thinker)s to cancel Actions with a reason.
+/// Dereferences to the [`ActionState`].
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub(crate) struct ActionStateQuery {
+    state: &'static mut ActionState,
+    cancellation: &'static mut Cancellation,
+}
+
+impl ActionStateQueryItem<'_> {
+    pub(crate) fn cancel_reason(&self) -> Option<CancelReason> {
+        self.cancellation.0
+    }
+
+    pub(crate) fn cancel(&mut self, reason: Option<CancelReason>) {
+        self.cancellation.0 = reason;
+        self.state.cancel();
+    }
+
+    pub(crate) fn cancel_if_executing(&mut self, reason: Option<CancelReason>) {
+        if self.state.is_executing() {
+            self.cancel(reason);
+        }
+    }
+}
+
+impl Deref for ActionStateQueryItem<'_> {
+    type Target = ActionState;
 
+    fn deref(&self) -> &ActionState {
+        &self.state
+    }
+}
+
+impl DerefMut for ActionStateQueryItem<'_> {
+    fn deref_mut(&mut self) -> &mut ActionState {
+        &mut self.state
+    }
+}
+
+impl Deref for ActionStateQueryReadOnlyItem<'_> {
+    type Target = ActionState;
+
+    fn deref(&self) -> &ActionState {
+        self.state
+    }
+}
+
+/// Optional data an Action finished with, next to its [`ActionState`]. This
+/// can be a reason code for an [`ActionState::Failure`] ("path blocked",
+/// "target dead"), or something the Action produced on
+/// [`ActionState::Success`], like a found [`Entity`].
+///
+/// Composite Actions adopt the outcome of the child Action that decided their
+/// own result, and [`Thinker`](crate::thinker::Thinker)s report it through
+/// [`ActionFinished`](crate::thinker::ActionFinished) events.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// #[derive(Debug, PartialEq)]
+/// enum PathError {
+///     Blocked,
+/// }
+///
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct MoveTo;
+/// fn move_to_action_system(mut query: Query<ActionQuery, With<MoveTo>>) {
+///     for mut action in query.iter_mut() {
+///         if action.is_executing() {
+///             action.failure_with(PathError::Blocked);
+///         }
+///     }
+/// }
+///
+/// fn report(mut events: EventReader<ActionFinished>) {
+///     for event in events.read() {
+///         if let Some(reason) = event.outcome.get::<PathError>() {
+///             warn!("{:?} failed: {:?}", event.action, reason);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Default)]
+pub struct ActionOutcome {
+    value: Option<Arc<dyn Any + Send + Sync>>,
+    type_name: &'static str,
+}
+
+impl ActionOutcome {
+    /// Make a new [`ActionOutcome`] holding `value`.
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self {
+            value: Some(Arc::new(value)),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Replaces the current value with `value`.
+    #[inline]
+    pub fn set<T: Any + Send + Sync>(&mut self, value: T) {
+        *self = Self::new(value);
+    }
+
+    /// Removes the current value.
     #[inline]
-    pub(crate) fn cancel_if_executing(&mut self) {
-        if matches!(self, Self::Executing) {
-            *self = Self::Cancelled;
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the current value, if there is one and it's a `T`.
+    #[inline]
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.value.as_deref()?.downcast_ref()
+    }
+
+    /// Returns true if there is a current value and it's a `T`.
+    #[inline]
+    pub fn is<T: Any>(&self) -> bool {
+        self.get::<T>().is_some()
+    }
+
+    /// Returns true if there is no current value.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+impl fmt::Debug for ActionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(_) => write!(f, "ActionOutcome({})", self.type_name),
+            None => write!(f, "ActionOutcome(None)"),
         }
     }
 }
 
+/// How far along an Action is, as reported by the Action itself. Useful for
+/// UI, debugging, or Scorers that don't want to interrupt Actions that are
+/// almost done.
+///
+/// Composite Actions combine the progress of their children: a
+/// [`Sequence`](crate::sequence::Sequence) of steps reports how many steps
+/// are done plus the progress of the current step, a joined one reports the
+/// mean progress of its children, and so on.
+#[derive(Debug, Clone, Copy, Component, Default, PartialEq, Reflect)]
+pub struct ActionProgress {
+    progress: f32,
+    eta: Option<Duration>,
+}
+
+impl ActionProgress {
+    /// Returns the progress, between `0.0..=1.0`.
+    #[inline]
+    pub fn get(&self) -> f32 {
+        self.progress
+    }
+
+    /// Returns the estimated time until the Action is done, if known.
+    #[inline]
+    pub fn eta(&self) -> Option<Duration> {
+        self.eta
+    }
+
+    /// Sets the progress. `progress` is clamped to `0.0..=1.0`, and NaN is
+    /// treated as `0.0`.
+    #[inline]
+    pub fn set(&mut self, progress: f32) {
+        self.progress = if progress.is_nan() {
+            0.0
+        } else {
+            progress.clamp(0.0, 1.0)
+        };
+    }
+
+    /// Sets the estimated time until the Action is done.
+    #[inline]
+    pub fn set_eta(&mut self, eta: Option<Duration>) {
+        self.eta = eta;
+    }
+}
+
 /// Trait that must be defined by types in order to be [`ActionSpawn`]s.
 /// [`ActionSpawn`]s' job is to spawn new `Action` entities on demand. In
 /// general, most of this is already done for you, and the only method you
@@ -229,6 +547,9 @@ all_tuples!(impl_actions_list, 1, 15, Type, index);
 #[query_data(mutable)]
 pub struct ActionQuery {
     state: &'static mut ActionState,
+    cancellation: &'static mut Cancellation,
+    outcome: &'static mut ActionOutcome,
+    progress: &'static mut ActionProgress,
     actor: &'static Actor,
 }
 
@@ -253,7 +574,21 @@ impl ActionQueryItem<'_> {
         self.state.is_done()
     }
 
+    /// Returns why the Action got cancelled. `None` if it wasn't, or if it
+    /// was cancelled without a reason.
+    pub fn cancel_reason(&self) -> Option<CancelReason> {
+        self.cancellation.reason()
+    }
+
     pub fn cancel(&mut self) {
+        self.cancellation.0 = None;
+        self.state.cancel();
+    }
+
+    /// Sets state to [`ActionState::Cancelled`], with `reason` as the
+    /// [`CancelReason`].
+    pub fn cancel_with(&mut self, reason: CancelReason) {
+        self.cancellation.0 = Some(reason);
         self.state.cancel();
     }
 
@@ -265,6 +600,38 @@ impl ActionQueryItem<'_> {
         self.state.failure();
     }
 
+    /// Sets state to [`ActionState::Success`], with `value` as the
+    /// [`ActionOutcome`].
+    pub fn success_with<T: Any + Send + Sync>(&mut self, value: T) {
+        self.outcome.set(value);
+        self.state.success();
+    }
+
+    /// Sets state to [`ActionState::Failure`], with `reason` as the
+    /// [`ActionOutcome`].
+    pub fn failure_with<T: Any + Send + Sync>(&mut self, reason: T) {
+        self.outcome.set(reason);
+        self.state.failure();
+    }
+
+    pub fn outcome(&self) -> &ActionOutcome {
+        &self.outcome
+    }
+
+    pub fn progress(&self) -> &ActionProgress {
+        &self.progress
+    }
+
+    /// Reports how far along the Action is, between `0.0..=1.0`.
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress.set(progress);
+    }
+
+    /// Reports the estimated time until the Action is done.
+    pub fn set_eta(&mut self, eta: Option<Duration>) {
+        self.progress.set_eta(eta);
+    }
+
     pub fn failure_if_cancelled(&mut self) {
         if self.state.is_cancelled() {
             self.state.failure()
@@ -281,6 +648,14 @@ impl ActionQueryReadOnlyItem<'_> {
         self.state.clone()
     }
 
+    pub fn outcome(&self) -> &ActionOutcome {
+        self.outcome
+    }
+
+    pub fn progress(&self) -> &ActionProgress {
+        self.progress
+    }
+
     pub fn is_executing(&self) -> bool {
         self.state.is_executing()
     }
@@ -292,4 +667,10 @@ impl ActionQueryReadOnlyItem<'_> {
     pub fn is_done(&self) -> bool {
         self.state.is_done()
     }
+
+    /// Returns why the Action got cancelled. `None` if it wasn't, or if it
+    /// was cancelled without a reason.
+    pub fn cancel_reason(&self) -> Option<CancelReason> {
+        self.cancellation.reason()
+    }
 }
@@ -0,0 +1,259 @@
+//! Extensions to [`App`] for registering Scorers and Actions with Big Brain.
+
+use crate::{
+    action::{self, ActionState},
+    hooks::{action_hooks_system, ActionHooks},
+    scorer::{CompositeScorer, CompositeScorers, Score, ScorePolicy, Warned},
+    BigBrainSet,
+};
+use bevy_app::App;
+use bevy_ecs::{
+    archetype::Archetypes,
+    component::{Component, Components},
+    intern::Interned,
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    system::{Local, Res, Resource},
+};
+use bevy_log as log;
+use bevy_reflect::GetTypeRegistration;
+use bevy_utils::HashSet;
+use std::any::TypeId;
+
+/// Schedules the [`BigBrainPlugin`](crate::BigBrainPlugin) was configured
+/// with.
+#[derive(Debug, Clone, Resource)]
+pub(crate) struct BigBrainSchedules {
+    pub(crate) scorers: Interned<dyn ScheduleLabel>,
+    pub(crate) actions: Interned<dyn ScheduleLabel>,
+}
+
+/// Scorer and Action component types that have a system driving them.
+#[derive(Debug, Default, Resource)]
+pub(crate) struct Registered {
+    types: HashSet<TypeId>,
+    checking: bool,
+}
+
+impl Registered {
+    /// Scorer and Action components that come with Big Brain.
+    pub(crate) fn builtin() -> Self {
+        use crate::*;
+
+        let types = [
+            TypeId::of::<scorer::IdleScorer>(),
+            TypeId::of::<FixedScorer>(),
+            TypeId::of::<AllOrNothing>(),
+            TypeId::of::<SumOfScorers>(),
+            TypeId::of::<ProductOfScorers>(),
+            TypeId::of::<CompensatedProductOfScorers>(),
+            TypeId::of::<WinningScorer>(),
+            TypeId::of::<EvaluatingScorer>(),
+            TypeId::of::<MeasuredScorer>(),
+            TypeId::of::<SmoothedScorer>(),
+            TypeId::of::<LatchScorer>(),
+            TypeId::of::<FnScorer>(),
+            TypeId::of::<FieldScorer>(),
+            TypeId::of::<Consideration>(),
+            TypeId::of::<MatchScorer>(),
+            TypeId::of::<TimeSinceScorer>(),
+            TypeId::of::<BoredomScorer>(),
+            TypeId::of::<Sequence>(),
+            TypeId::of::<Finally>(),
+            TypeId::of::<Guard>(),
+            TypeId::of::<Selector>(),
+            TypeId::of::<StateMachine>(),
+            TypeId::of::<Waiting>(),
+            TypeId::of::<FnAction>(),
+        ];
+
+        Self {
+            types: types.into_iter().collect(),
+            checking: false,
+        }
+    }
+}
+
+/// Big Brain extensions for [`App`]. Requires the
+/// [`BigBrainPlugin`](crate::BigBrainPlugin) to be added first.
+///
+/// Registering Scorers and Actions through [`BigBrainApp::register_scorer`]
+/// and [`BigBrainApp::register_action`] (instead of adding their systems by
+/// hand) also turns on a check that warns about Scorers and Actions whose
+/// components don't have a registered system, since those never do anything.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// #[derive(Clone, Component, Reflect, ScorerSpawn)]
+/// struct Thirsty;
+///
+/// fn thirsty_scorer_system(mut query: Query<ScorerQuery, With<Thirsty>>) {
+///     // ...
+/// }
+///
+/// #[derive(Clone, Component, Reflect, ActionSpawn)]
+/// struct Drink;
+///
+/// fn drink_action_system(mut query: Query<ActionQuery, With<Drink>>) {
+///     // ...
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((
+///         MinimalPlugins,
+///         AssetPlugin::default(),
+///         BigBrainPlugin::new(Update, Update, PostUpdate, Last),
+///     ))
+///     .register_scorer::<Thirsty, _>(thirsty_scorer_system)
+///     .register_action::<Drink, _>(drink_action_system);
+/// # }
+/// ```
+pub trait BigBrainApp {
+    /// Adds `system` to [`BigBrainSet::Scorers`], and registers `T` as the
+    /// Scorer component it drives, including for reflection.
+    fn register_scorer<T, M>(&mut self, system: impl IntoSystemConfigs<M>) -> &mut Self
+    where
+        T: Component + GetTypeRegistration;
+
+    /// Adds `system` to [`BigBrainSet::Actions`], and registers `T` as the
+    /// Action component it drives, including for reflection.
+    fn register_action<T, M>(&mut self, system: impl IntoSystemConfigs<M>) -> &mut Self
+    where
+        T: Component + GetTypeRegistration;
+
+    /// Schedules the system that drives `T` Actions through their
+    /// [`ActionHooks`], in [`BigBrainSet::Actions`].
+    fn add_action_hooks<T: ActionHooks>(&mut self) -> &mut Self;
+
+    /// Registers `T` as a [`CompositeScorer`], so Big Brain keeps its
+    /// [`Score`] up to date.
+    fn register_composite_scorer<T: CompositeScorer>(&mut self) -> &mut Self;
+}
+
+impl BigBrainApp for App {
+    fn register_scorer<T, M>(&mut self, system: impl IntoSystemConfigs<M>) -> &mut Self
+    where
+        T: Component + GetTypeRegistration,
+    {
+        let schedules = register::<T>(self, true);
+        self.register_type::<T>()
+            .add_systems(schedules.scorers, system.in_set(BigBrainSet::Scorers))
+    }
+
+    fn register_action<T, M>(&mut self, system: impl IntoSystemConfigs<M>) -> &mut Self
+    where
+        T: Component + GetTypeRegistration,
+    {
+        let schedules = register::<T>(self, true);
+        self.register_type::<T>()
+            .add_systems(schedules.actions, system.in_set(BigBrainSet::Actions))
+    }
+
+    fn add_action_hooks<T: ActionHooks>(&mut self) -> &mut Self {
+        let schedules = register::<T>(self, false);
+        self.add_systems(
+            schedules.actions,
+            action_hooks_system::<T>.in_set(BigBrainSet::Actions),
+        )
+    }
+
+    fn register_composite_scorer<T: CompositeScorer>(&mut self) -> &mut Self {
+        register::<T>(self, false);
+        CompositeScorers::register::<T>(self.world_mut());
+        self
+    }
+}
+
+/// Marks `T` as registered, and turns on [`unregistered_check_system`] if
+/// asked to.
+fn register<T: Component>(app: &mut App, check: bool) -> BigBrainSchedules {
+    let schedules = app
+        .world()
+        .get_resource::<BigBrainSchedules>()
+        .expect("BigBrainPlugin has to be added first")
+        .clone();
+
+    let mut registered = app.world_mut().resource_mut::<Registered>();
+    registered.types.insert(TypeId::of::<T>());
+    if check && !registered.checking {
+        registered.checking = true;
+        app.add_systems(
+            schedules.scorers,
+            unregistered_check_system.in_set(BigBrainSet::Thinker),
+        );
+    }
+
+    schedules
+}
+
+/// System that warns (once per component type) about Scorers and Actions
+/// without any registered component.
+///
+/// Only archetypes created since the last run are looked at, so once every
+/// kind of Scorer and Action has been spawned this costs next to nothing.
+/// Archetypes that are already empty again, which entities only passed
+/// through while their components were being inserted, are skipped.
+fn unregistered_check_system(
+    archetypes: &Archetypes,
+    components: &Components,
+    registered: Res<Registered>,
+    mut checked: Local<usize>,
+    mut warned: Local<HashSet<TypeId>>,
+) {
+    let (Some(score), Some(action_state)) = (
+        components.component_id::<Score>(),
+        components.component_id::<ActionState>(),
+    ) else {
+        return;
+    };
+    let bookkeeping = [
+        TypeId::of::<Score>(),
+        TypeId::of::<ScorePolicy>(),
+        TypeId::of::<Warned>(),
+    ]
+    .into_iter()
+    .chain(action::bookkeeping())
+    .collect::<Vec<_>>();
+
+    for archetype in archetypes.iter().skip(*checked) {
+        let is_scorer = archetype.contains(score);
+        let Some(entity) = archetype.entities().first() else {
+            continue;
+        };
+        if !is_scorer && !archetype.contains(action_state) {
+            continue;
+        }
+
+        let components = archetype
+            .components()
+            .filter_map(|id| components.get_info(id))
+            .filter_map(|info| Some((info.type_id()?, info.name())))
+            .filter(|(type_id, _)| !bookkeeping.contains(type_id))
+            .collect::<Vec<_>>();
+
+        let unknown = components
+            .iter()
+            .all(|(type_id, _)| !registered.types.contains(type_id));
+        if !unknown
+            || components
+                .iter()
+                .all(|(type_id, _)| warned.contains(type_id))
+        {
+            continue;
+        }
+
+        let names = components.iter().map(|&(_, name)| name).collect::<Vec<_>>();
+        log::warn!(
+            "{} {:?} has no registered system for any of its components ({}), so it will never {}",
+            if is_scorer { "Scorer" } else { "Action" },
+            entity.id(),
+            names.join(", "),
+            if is_scorer { "score" } else { "finish" },
+        );
+        warned.extend(components.into_iter().map(|(type_id, _)| type_id));
+    }
+    *checked = archetypes.len();
+}
@@ -0,0 +1,125 @@
+//! Scorers and Actions defined by closures instead of a component and a
+//! system each. Handy for prototyping, in the same spirit as
+//! [`FnEvaluator`](crate::evaluator::FnEvaluator).
+
+use crate::{
+    action::{Action, ActionCommands, ActionSpawn, ActionState},
+    hooks::ActionStatus,
+    scorer::{Score, Scorer, ScorerCommands, ScorerSpawn},
+    thinker::Actor,
+};
+use bevy_ecs::{
+    component::Component, entity::Entity, query::QueryState, system::Local, world::World,
+};
+use std::sync::Arc;
+
+/// Scorer that calls a closure with the actor and read-only access to the
+/// [`World`] every frame, and uses its result as the [`Score`].
+///
+/// All [`FnScorer`]s run in a single exclusive system, so prefer a component
+/// and a regular system once the behavior settles down.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// #[derive(Component)]
+/// struct Thirst(f32);
+///
+/// # fn main() {
+/// ThinkerSpawner::highest(0.5).when(
+///     FnScorer::new(|actor, world| {
+///         world.get::<Thirst>(actor).map_or(0.0, |thirst| thirst.0 / 100.0)
+///     }),
+///     FnAction::new(|actor, _state, world| {
+///         world.get_mut::<Thirst>(actor).unwrap().0 = 0.0;
+///         ActionStatus::Success
+///     }),
+/// )
+/// # ;
+/// # }
+/// ```
+#[derive(Clone, Component)]
+pub struct FnScorer(Arc<ScorerFn>);
+
+type ScorerFn = dyn (Fn(Entity, &World) -> f32) + Sync + Send;
+
+impl FnScorer {
+    pub fn new(f: impl (Fn(Entity, &World) -> f32) + Sync + Send + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl ScorerSpawn for FnScorer {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn(self.clone())
+    }
+}
+
+pub fn fn_scorer_system(
+    world: &mut World,
+    query: &mut QueryState<(Entity, &Actor, &FnScorer)>,
+    mut scores: Local<Vec<(Entity, f32)>>,
+) {
+    scores.extend(
+        query
+            .iter(world)
+            .map(|(entity, actor, FnScorer(f))| (entity, f(actor.entity(), world))),
+    );
+    for (entity, value) in scores.drain(..) {
+        if let Some(mut score) = world.get_mut::<Score>(entity) {
+            score.set(value);
+        }
+    }
+}
+
+/// Action that calls a closure with the actor, its current [`ActionState`]
+/// (either [`ActionState::Executing`] or [`ActionState::Cancelled`]), and
+/// mutable access to the [`World`] every frame, until the closure returns
+/// something other than [`ActionStatus::Running`]. See [`FnScorer`] for an
+/// example.
+///
+/// All [`FnAction`]s run in a single exclusive system, so prefer a component
+/// and a regular system once the behavior settles down.
+#[derive(Clone, Component)]
+pub struct FnAction(Arc<ActionFn>);
+
+type ActionFn = dyn (Fn(Entity, ActionState, &mut World) -> ActionStatus) + Sync + Send;
+
+impl FnAction {
+    pub fn new(
+        f: impl (Fn(Entity, ActionState, &mut World) -> ActionStatus) + Sync + Send + 'static,
+    ) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl ActionSpawn for FnAction {
+    fn spawn(&self, mut cmd: ActionCommands) -> Action {
+        cmd.spawn(self.clone())
+    }
+}
+
+pub fn fn_action_system(
+    world: &mut World,
+    query: &mut QueryState<(Entity, &Actor, &ActionState, &FnAction)>,
+    mut actions: Local<Vec<(Entity, Entity, ActionState, FnAction)>>,
+) {
+    actions.extend(
+        query
+            .iter(world)
+            .filter(|(_, _, state, _)| !state.is_done())
+            .map(|(entity, actor, state, f)| (entity, actor.entity(), state.clone(), f.clone())),
+    );
+    for (entity, actor, state, FnAction(f)) in actions.drain(..) {
+        let state = match f(actor, state, world) {
+            ActionStatus::Running => continue,
+            ActionStatus::Success => ActionState::Success,
+            ActionStatus::Failure => ActionState::Failure,
+        };
+        if let Some(mut current) = world.get_mut::<ActionState>(entity) {
+            *current = state;
+        }
+    }
+}
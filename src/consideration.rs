@@ -0,0 +1,248 @@
+//! Infinite Axis Utility System-style Considerations: a raw input, clamped
+//! between two bookends and normalized, shaped by a response curve, and
+//! clamped again. Combine them with [`ProductOfScorers`](crate::ProductOfScorers)
+//! or [`CompensatedProductOfScorers`](crate::CompensatedProductOfScorers) to
+//! score a decision.
+
+use crate::{
+    evaluator::Evaluator,
+    field::{normalize, Field},
+    history::{Activity, Histories},
+    scorer::{Score, Scorer, ScorerCommands, ScorerSpawn, Warned},
+    thinker::Actor,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{Has, QueryState},
+    reflect::AppTypeRegistry,
+    system::{Local, SystemState},
+    world::World,
+};
+use bevy_log as log;
+use bevy_time::Time;
+#[cfg(feature = "transform")]
+use bevy_transform::components::GlobalTransform;
+use std::sync::Arc;
+
+type InputFn = dyn (Fn(Entity, &World) -> Option<f32>) + Sync + Send;
+#[cfg(feature = "transform")]
+type TargetFn = dyn (Fn(Entity, &World) -> Option<Entity>) + Sync + Send;
+
+/// Where a [`Consideration`] gets its raw input from.
+#[derive(Clone)]
+pub struct ConsiderationInput(InputKind);
+
+#[derive(Clone)]
+enum InputKind {
+    Field(Field),
+    #[cfg(feature = "transform")]
+    Distance(Arc<TargetFn>),
+    TimeSince(Activity),
+    Custom(Arc<InputFn>),
+}
+
+impl ConsiderationInput {
+    /// A numeric field of the `T` component on the Actor, read through
+    /// reflection like a [`FieldScorer`](crate::FieldScorer).
+    ///
+    /// Panics if `path` isn't a valid reflection path.
+    pub fn field<T: Component>(path: &str) -> Self {
+        Self(InputKind::Field(Field::new::<T>(path)))
+    }
+
+    /// Like [`ConsiderationInput::field`], but finds the component by its full
+    /// type path.
+    pub fn field_from_type_path(component: &str, path: &str) -> Self {
+        Self(InputKind::Field(Field::from_type_path(component, path)))
+    }
+
+    /// Distance between the [`GlobalTransform`]s of the Actor and the entity
+    /// `target` picks for it. Needs the `transform` feature.
+    #[cfg(feature = "transform")]
+    pub fn distance(
+        target: impl (Fn(Entity, &World) -> Option<Entity>) + Sync + Send + 'static,
+    ) -> Self {
+        Self(InputKind::Distance(Arc::new(target)))
+    }
+
+    /// Seconds since the Actor last finished `activity`. See
+    /// [`TimeSinceScorer`](crate::TimeSinceScorer).
+    pub fn time_since(activity: Activity) -> Self {
+        Self(InputKind::TimeSince(activity))
+    }
+
+    /// Whatever the closure returns for the Actor.
+    pub fn custom(input: impl (Fn(Entity, &World) -> Option<f32>) + Sync + Send + 'static) -> Self {
+        Self(InputKind::Custom(Arc::new(input)))
+    }
+
+    /// Reads the raw input for `actor`. `Ok(None)` when there's nothing to
+    /// read, like a missing component or target.
+    fn read(
+        &self,
+        world: &World,
+        histories: &Histories,
+        actor: Entity,
+    ) -> Result<Option<f32>, String> {
+        match &self.0 {
+            InputKind::Field(field) => {
+                let registry = world
+                    .get_resource::<AppTypeRegistry>()
+                    .ok_or("no AppTypeRegistry")?
+                    .read();
+                field
+                    .read(&registry, world, actor)
+                    .map_err(|err| format!("reading {}: {err}", field.component()))
+            }
+            #[cfg(feature = "transform")]
+            InputKind::Distance(target) => {
+                let position = |entity| world.get::<GlobalTransform>(entity);
+                let distance = target(actor, world).and_then(|target| {
+                    let (from, to) = (position(actor)?, position(target)?);
+                    Some(from.translation().distance(to.translation()))
+                });
+                Ok(distance)
+            }
+            InputKind::TimeSince(activity) => {
+                let now = world.get_resource::<Time>().ok_or("no Time")?.elapsed();
+                let since = histories.since(actor, *activity, now);
+                Ok(since.map(|since| since.as_secs_f32()))
+            }
+            InputKind::Custom(input) => Ok(input(actor, world)),
+        }
+    }
+}
+
+/// Scorer that turns a raw [`ConsiderationInput`] into a [`Score`] in the
+/// usual Infinite Axis Utility System steps:
+///
+/// 1. Read the raw input, like a component field or a distance.
+/// 2. Clamp it between the `min` and `max` bookends, and normalize it to
+///    `0.0..=1.0`. Swap the bookends to invert it.
+/// 3. Shape it with a response curve, any [`Evaluator`]. Linear if not set.
+/// 4. Clamp the result, to `0.0..=1.0` unless configured otherwise.
+///
+/// When there's no input to read, like when the Actor doesn't have the
+/// component, a [`Consideration`] Scores `0.0`. Misconfigured ones, like a
+/// field path that doesn't exist, also log a warning once.
+///
+/// All [`Consideration`]s run in a single exclusive system.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Health(f32);
+///
+/// #[derive(Component)]
+/// struct Enemy(Entity);
+///
+/// #[derive(Component)]
+/// struct Strength(f32);
+///
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Flee;
+/// # fn main() {
+/// // Flee when hurt, the stronger the enemy the more so.
+/// let health = ConsiderationInput::field::<Health>("0");
+/// let hurt = Consideration::new(health, 100.0, 0.0)
+///     .curve(Power::new(2.0, (0.0, 0.0), (1.0, 1.0)));
+/// let enemy = ConsiderationInput::custom(|actor, world| {
+///     let Enemy(enemy) = world.get::<Enemy>(actor)?;
+///     world.get::<Strength>(*enemy).map(|strength| strength.0)
+/// });
+/// let strong = Consideration::new(enemy, 0.0, 10.0).clamp(0.1, 1.0);
+///
+/// ThinkerSpawner::highest(0.3).when(ProductOfScorers::build(0.0, (hurt, strong)), Flee)
+/// # ;
+/// # }
+/// ```
+#[derive(Clone, Component)]
+pub struct Consideration {
+    input: ConsiderationInput,
+    min: f32,
+    max: f32,
+    curve: Option<Arc<dyn Evaluator>>,
+    clamp: (f32, f32),
+}
+
+impl Consideration {
+    /// Considers `input` between the `min` and `max` bookends.
+    pub fn new(input: ConsiderationInput, min: f32, max: f32) -> Self {
+        Self {
+            input,
+            min,
+            max,
+            curve: None,
+            clamp: (0.0, 1.0),
+        }
+    }
+
+    /// Shapes the normalized input with `curve`.
+    pub fn curve(mut self, curve: impl Evaluator + 'static) -> Self {
+        self.curve = Some(Arc::new(curve));
+        self
+    }
+
+    /// Clamps the output between `min` and `max` instead of `0.0..=1.0`, like
+    /// a minimum so that one Consideration can't veto a whole decision on
+    /// its own.
+    pub fn clamp(mut self, min: f32, max: f32) -> Self {
+        self.clamp = (min.min(max), min.max(max));
+        self
+    }
+
+    /// Runs `value` through the bookends, the response curve, and the
+    /// output clamp.
+    pub fn score(&self, value: f32) -> f32 {
+        let value = normalize(value, self.min, self.max);
+        let value = self
+            .curve
+            .as_ref()
+            .map_or(value, |curve| curve.evaluate(value));
+        let (min, max) = self.clamp;
+        value.clamp(min, max)
+    }
+}
+
+impl ScorerSpawn for Consideration {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn(self.clone())
+    }
+}
+
+pub(crate) fn consideration_system(
+    world: &mut World,
+    query: &mut QueryState<(Entity, &Actor, &Consideration, Has<Warned>)>,
+    mut histories: Local<SystemState<Histories<'static, 'static>>>,
+    mut scores: Local<Vec<(Entity, f32, bool)>>,
+) {
+    let histories = histories.get(world);
+    for (entity, actor, consideration, warned) in query.iter(world) {
+        let mut warn = false;
+        let value = match consideration.input.read(world, &histories, actor.entity()) {
+            Ok(value) => value.map_or(0.0, |value| consideration.score(value)),
+            Err(err) => {
+                if !warned {
+                    warn = true;
+                    log::warn!("Consideration {entity:?}: {err}");
+                }
+                0.0
+            }
+        };
+        scores.push((entity, value, warn));
+    }
+
+    for (entity, value, warn) in scores.drain(..) {
+        if warn {
+            world.entity_mut(entity).insert(Warned);
+        }
+        if let Some(mut score) = world.get_mut::<Score>(entity) {
+            score.set(value);
+        }
+    }
+}
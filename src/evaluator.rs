@@ -1,13 +1,14 @@
 //! Utilities for turning values within a certain range into different curves.
 
-use crate::scorer::{Score, ScorerSpawn, ScorerSpawner};
-use bevy_ecs::{component::Component, entity::Entity, system::Query};
-use bevy_hierarchy::Children;
+use crate::scorer::{CompositeScorer, ScorerSpawn, ScorerSpawner};
+use bevy_ecs::component::Component;
 use bevy_reflect::{reflect_trait, Reflect};
 use std::sync::Arc;
 
-/// Trait that any evaluators must implement.
-/// Must return an `f32` value between `0.0..=100.0`.
+/// Trait that any evaluators must implement. Evaluators are response curves:
+/// they map a value, usually already normalized to `0.0..=1.0`, to a
+/// [`Score`](crate::Score) between `0.0..=1.0`. Results outside that range
+/// are handled by the [`ScorePolicy`](crate::ScorePolicy).
 #[reflect_trait]
 pub trait Evaluator: Sync + Send {
     fn evaluate(&self, value: f32) -> f32;
@@ -52,15 +53,11 @@ impl EvaluatingScorer {
     }
 }
 
-pub fn evaluating_scorer_system(
-    query: Query<(Entity, &EvaluatingScorer, &Children)>,
-    mut scores: Query<&mut Score>,
-) {
-    for (this_entity, this, children) in query.iter() {
-        let &inner = children.first().unwrap();
-        let &Score(inner) = scores.get(inner).unwrap();
-        let value = this.0.evaluate(inner).clamp(0.0, 1.0);
-        scores.get_mut(this_entity).unwrap().set(value);
+impl CompositeScorer for EvaluatingScorer {
+    fn combine(&self, children: &[f32]) -> f32 {
+        children
+            .first()
+            .map_or(0.0, |&inner| self.0.evaluate(inner).clamp(0.0, 1.0))
     }
 }
 
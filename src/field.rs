@@ -0,0 +1,263 @@
+//! Scorers that read a number straight out of one of the Actor's components
+//! through reflection, so simple considerations can be authored as data
+//! instead of a component and a system each.
+
+use crate::{
+    evaluator::Evaluator,
+    scorer::{Score, Scorer, ScorerCommands, ScorerSpawn, Warned},
+    thinker::Actor,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{Has, QueryState},
+    reflect::{AppTypeRegistry, ReflectComponent},
+    system::Local,
+    world::World,
+};
+use bevy_log as log;
+use bevy_reflect::{ParsedPath, PartialReflect, ReflectPath, TypeRegistry};
+use std::{
+    any::{type_name, TypeId},
+    sync::Arc,
+};
+
+/// The component a [`FieldScorer`] reads from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ComponentType {
+    Id(TypeId, &'static str),
+    Path(String),
+}
+
+impl ComponentType {
+    fn name(&self) -> &str {
+        match self {
+            Self::Id(_, name) => name,
+            Self::Path(path) => path,
+        }
+    }
+}
+
+/// Scorer that reads a numeric field of a component on the Actor through
+/// reflection, and maps it from an input range to `0.0..=1.0`. The result can
+/// optionally be shaped further with an [`Evaluator`].
+///
+/// The component type must be registered for reflection with
+/// `#[reflect(Component)]`. Fields can be any of the primitive number types,
+/// and are found with a [`bevy_reflect`] path like `"thirst"` or
+/// `"stats.fatigue"`. Actors without the component Score `0.0`, and
+/// misconfigured Scorers Score `0.0` and log a warning once.
+///
+/// All [`FieldScorer`]s run in a single exclusive system.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Thirst {
+///     thirst: f32,
+/// }
+///
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Drink;
+/// # fn main() {
+/// App::new()
+///     .add_plugins((
+///         MinimalPlugins,
+///         AssetPlugin::default(),
+///         BigBrainPlugin::new(Update, Update, PostUpdate, Last),
+///     ))
+///     .register_type::<Thirst>();
+///
+/// ThinkerSpawner::highest(0.5).when(
+///     FieldScorer::new::<Thirst>("thirst", 0.0, 100.0).evaluator(Power::default()),
+///     Drink,
+/// )
+/// # ;
+/// # }
+/// ```
+#[derive(Clone, Component)]
+pub struct FieldScorer {
+    field: Field,
+    min: f32,
+    max: f32,
+    evaluator: Option<Arc<dyn Evaluator>>,
+}
+
+impl FieldScorer {
+    /// Reads the field at `path` of the `T` component, mapping `min..=max`
+    /// to `0.0..=1.0`.
+    ///
+    /// Panics if `path` isn't a valid reflection path.
+    pub fn new<T: Component>(path: &str, min: f32, max: f32) -> Self {
+        Self::build(Field::new::<T>(path), min, max)
+    }
+
+    /// Like [`FieldScorer::new`], but finds the component by its full type
+    /// path, like `"my_game::needs::Thirst"`, for considerations loaded from
+    /// data.
+    ///
+    /// Panics if `path` isn't a valid reflection path.
+    pub fn from_type_path(component: &str, path: &str, min: f32, max: f32) -> Self {
+        Self::build(Field::from_type_path(component, path), min, max)
+    }
+
+    fn build(field: Field, min: f32, max: f32) -> Self {
+        Self {
+            field,
+            min,
+            max,
+            evaluator: None,
+        }
+    }
+
+    /// Shapes the normalized value with `evaluator`.
+    pub fn evaluator(mut self, evaluator: impl Evaluator + 'static) -> Self {
+        self.evaluator = Some(Arc::new(evaluator));
+        self
+    }
+
+    fn score(&self, value: f32) -> f32 {
+        let value = normalize(value, self.min, self.max);
+        self.evaluator
+            .as_ref()
+            .map_or(value, |evaluator| evaluator.evaluate(value))
+    }
+}
+
+impl ScorerSpawn for FieldScorer {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn(self.clone())
+    }
+}
+
+/// A numeric field of a component, found through reflection.
+#[derive(Clone)]
+pub(crate) struct Field {
+    component: ComponentType,
+    path: Arc<ParsedPath>,
+}
+
+impl Field {
+    pub(crate) fn new<T: Component>(path: &str) -> Self {
+        Self::build(ComponentType::Id(TypeId::of::<T>(), type_name::<T>()), path)
+    }
+
+    pub(crate) fn from_type_path(component: &str, path: &str) -> Self {
+        Self::build(ComponentType::Path(component.into()), path)
+    }
+
+    fn build(component: ComponentType, path: &str) -> Self {
+        let parsed = ParsedPath::parse(path)
+            .unwrap_or_else(|err| panic!("invalid field path {path:?}: {err}"));
+        Self {
+            component,
+            path: Arc::new(parsed),
+        }
+    }
+
+    pub(crate) fn component(&self) -> &str {
+        self.component.name()
+    }
+
+    /// Reads the field from `actor`. `Ok(None)` if the Actor doesn't have
+    /// the component.
+    pub(crate) fn read(
+        &self,
+        registry: &TypeRegistry,
+        world: &World,
+        actor: Entity,
+    ) -> Result<Option<f32>, String> {
+        let registration = match &self.component {
+            ComponentType::Id(id, _) => registry.get(*id),
+            ComponentType::Path(path) => registry.get_with_type_path(path),
+        };
+        let registration = registration.ok_or("component type isn't registered")?;
+        let reflect = registration
+            .data::<ReflectComponent>()
+            .ok_or("component type doesn't #[reflect(Component)]")?;
+
+        let Ok(actor) = world.get_entity(actor) else {
+            return Ok(None);
+        };
+        let Some(component) = reflect.reflect(actor) else {
+            return Ok(None);
+        };
+        let field = self
+            .path
+            .as_ref()
+            .reflect_element(component.as_partial_reflect())
+            .map_err(|err| err.to_string())?;
+        number(field).map(Some).ok_or_else(|| {
+            let ty = field.reflect_type_path();
+            format!("field is a {ty}, not a number")
+        })
+    }
+}
+
+/// Maps `value` from `min..=max` to `0.0..=1.0`. `min` may be larger than
+/// `max` to invert the mapping.
+pub(crate) fn normalize(value: f32, min: f32, max: f32) -> f32 {
+    if max == min {
+        if value >= max {
+            1.0
+        } else {
+            0.0
+        }
+    } else {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    }
+}
+
+/// Reads any primitive number as an `f32`.
+fn number(field: &dyn PartialReflect) -> Option<f32> {
+    macro_rules! numbers {
+        ($($ty:ty),*) => {
+            $(if let Some(&value) = field.try_downcast_ref::<$ty>() {
+                return Some(value as f32);
+            })*
+        };
+    }
+
+    numbers!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    None
+}
+
+pub(crate) fn field_scorer_system(
+    world: &mut World,
+    query: &mut QueryState<(Entity, &Actor, &FieldScorer, Has<Warned>)>,
+    mut scores: Local<Vec<(Entity, f32, bool)>>,
+) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+        return;
+    };
+    let registry = registry.read();
+    for (entity, actor, scorer, warned) in query.iter(world) {
+        let mut warn = false;
+        let value = match scorer.field.read(&registry, world, actor.entity()) {
+            Ok(value) => value.map_or(0.0, |value| scorer.score(value)),
+            Err(err) => {
+                if !warned {
+                    warn = true;
+                    let component = scorer.field.component();
+                    log::warn!("FieldScorer {entity:?} reading {component}: {err}");
+                }
+                0.0
+            }
+        };
+        scores.push((entity, value, warn));
+    }
+    drop(registry);
+
+    for (entity, value, warn) in scores.drain(..) {
+        if warn {
+            world.entity_mut(entity).insert(Warned);
+        }
+        if let Some(mut score) = world.get_mut::<Score>(entity) {
+            score.set(value);
+        }
+    }
+}
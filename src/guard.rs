@@ -0,0 +1,158 @@
+//! Guarded Actions, which get cancelled as soon as a Scorer drops below a
+//! threshold.
+
+use crate::{
+    action::{
+        Action, ActionCommands, ActionOutcome, ActionProgress, ActionSpawn, ActionState,
+        ActionStateQuery, CancelReason,
+    },
+    scorer::{Score, Scorer, ScorerSpawn},
+};
+use bevy_ecs::{
+    change_detection::DetectChanges, component::Component, entity::Entity, system::Query,
+    world::Ref,
+};
+use bevy_log as log;
+use std::sync::Arc;
+
+/// [`ActionSpawn`] for the [`Guard`] component.
+pub struct GuardSpawner {
+    when: Arc<dyn ScorerSpawn>,
+    threshold: f32,
+    then: Arc<dyn ActionSpawn>,
+    on_drop: ActionState,
+}
+
+impl GuardSpawner {
+    /// End in [`ActionState::Success`] instead of [`ActionState::Failure`]
+    /// when the guarded action gets cancelled because the guard dropped.
+    pub fn succeed_on_drop(mut self) -> Self {
+        self.on_drop = ActionState::Success;
+        self
+    }
+}
+
+impl ActionSpawn for GuardSpawner {
+    fn spawn(&self, mut cmd: ActionCommands) -> Action {
+        let guard = cmd.spawn(());
+        let scorer = cmd.push_scorer(guard, self.when.as_ref());
+        let action = cmd.push_child(guard, self.then.as_ref());
+
+        cmd.insert(
+            guard,
+            Guard {
+                threshold: self.threshold,
+                on_drop: self.on_drop.clone(),
+                scorer,
+                action,
+                evaluated: false,
+                dropped: false,
+            },
+        );
+
+        guard
+    }
+}
+
+/// Composite Action that runs an Action for as long as a guard Scorer stays
+/// at or above a `threshold`. The guard is checked every frame, and once it
+/// drops below the `threshold`, the Action gets cancelled and the [`Guard`]
+/// ends in [`ActionState::Failure`] (or [`ActionState::Success`], see
+/// [`GuardSpawner::succeed_on_drop`]).
+///
+/// If the Action finishes on its own, the [`Guard`] ends with the same
+/// result. Either way, the [`Guard`] adopts the [`ActionOutcome`] and
+/// [`ActionProgress`] of the Action.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct Hunting;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct TargetVisible;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Chase;
+/// # fn main() {
+/// ThinkerSpawner::highest(0.0)
+///     .when(Hunting, Guard::build(TargetVisible, 0.3, Chase))
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct Guard {
+    threshold: f32,
+    on_drop: ActionState,
+    scorer: Scorer,
+    action: Action,
+    evaluated: bool,
+    dropped: bool,
+}
+
+impl Guard {
+    /// Construct a new [`GuardSpawner`] that runs `then` for as long as
+    /// `when` scores at or above `threshold`.
+    pub fn build(
+        when: impl ScorerSpawn + 'static,
+        threshold: f32,
+        then: impl ActionSpawn + 'static,
+    ) -> GuardSpawner {
+        GuardSpawner {
+            when: Arc::new(when),
+            threshold,
+            then: Arc::new(then),
+            on_drop: ActionState::Failure,
+        }
+    }
+}
+
+/// System that takes care of executing any existing [`Guard`] Actions.
+pub fn guard_system(
+    mut query: Query<(Entity, &mut Guard)>,
+    mut states: Query<ActionStateQuery>,
+    mut outcomes: Query<&mut ActionOutcome>,
+    mut progress: Query<&mut ActionProgress>,
+    scores: Query<Ref<Score>>,
+) {
+    for (entity, mut guard) in query.iter_mut() {
+        let entities = [entity, guard.action.entity()];
+        let Ok([mut this_state, mut child]) = states.get_many_mut(entities) else {
+            continue;
+        };
+
+        // The guard Score starts out at 0.0, so wait until its Scorer had a
+        // chance to run before looking at it.
+        let score = scores.get(guard.scorer.0).unwrap();
+        guard.evaluated |= score.is_changed() && !score.is_added();
+
+        let [mut this_progress, child_progress] = progress.many_mut(entities);
+        *this_progress = *child_progress;
+
+        match (this_state.clone(), child.clone()) {
+            (ActionState::Executing, ActionState::Executing) => {
+                if guard.evaluated && score.get() < guard.threshold {
+                    log::debug!("{:?} guard dropped to {}", entity, score.get());
+                    guard.dropped = true;
+                    child.cancel(Some(CancelReason::GuardDropped));
+                }
+            }
+            (ActionState::Executing | ActionState::Cancelled, ActionState::Cancelled) => (),
+            (ActionState::Cancelled, ActionState::Executing) => {
+                child.cancel(this_state.cancel_reason())
+            }
+            (ActionState::Executing | ActionState::Cancelled, result) => {
+                log::trace!("{:?} guarded action ended with {:?}", entity, result);
+                let [mut this_outcome, child_outcome] = outcomes.many_mut(entities);
+                *this_outcome = child_outcome.clone();
+                *this_state = if guard.dropped {
+                    guard.on_drop.clone()
+                } else {
+                    result
+                };
+            }
+            (ActionState::Success | ActionState::Failure, _) => (),
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Thinkers remember when their Actions finished, so Scorers can make
+//! decisions based on what an Actor did recently, like "urgency grows the
+//! longer we haven't eaten" or "boredom grows with repetition".
+
+use crate::{
+    action::{self, ActionState},
+    evaluator::Evaluator,
+    scorer::{Scorer, ScorerCommands, ScorerQuery, ScorerSpawn},
+    thinker::{Actor, HasThinker, Thinker},
+};
+use bevy_ecs::{
+    archetype::Archetypes,
+    component::{Component, ComponentId, Components},
+    entity::{Entities, Entity},
+    system::{Query, Res, SystemParam},
+    world::DeferredWorld,
+};
+use bevy_hierarchy::Parent;
+use bevy_time::Time;
+use bevy_utils::HashMap;
+use std::{any::TypeId, collections::VecDeque, sync::Arc, time::Duration};
+
+/// Something a [`Thinker`] ran, as remembered by [`ThinkerHistory`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Activity {
+    /// An Action picked for the [`ThinkerSpawner::when`](crate::ThinkerSpawner::when)
+    /// choice with this index.
+    Choice(usize),
+    /// An Action with a component of this type, whether it was picked or
+    /// [scheduled](Thinker::schedule).
+    Action(TypeId),
+}
+
+impl Activity {
+    /// An Action with a `T` component.
+    pub fn action<T: Component>() -> Self {
+        Self::Action(TypeId::of::<T>())
+    }
+}
+
+/// When a [`Thinker`]'s Actions finished, measured with [`Time`]. Actions
+/// nested in Composite Actions, like the steps of a
+/// [`Sequence`](crate::Sequence), are recorded as they get despawned.
+///
+/// The last completion of every [`Activity`] is kept forever, while the full
+/// record only keeps the most recent [`ThinkerHistory::CAPACITY`]
+/// completions.
+#[derive(Debug, Clone, Default)]
+pub struct ThinkerHistory {
+    started: Option<Duration>,
+    last: HashMap<Activity, Duration>,
+    completions: VecDeque<(Duration, Vec<Activity>)>,
+}
+
+impl ThinkerHistory {
+    /// How many completions [`ThinkerHistory::count_since`] can look back on.
+    pub const CAPACITY: usize = 64;
+
+    pub(crate) fn start(&mut self, now: Duration) {
+        self.started.get_or_insert(now);
+    }
+
+    pub(crate) fn record(&mut self, now: Duration, activities: Vec<Activity>) {
+        for &activity in &activities {
+            self.last.insert(activity, now);
+        }
+        if self.completions.len() == Self::CAPACITY {
+            self.completions.pop_front();
+        }
+        self.completions.push_back((now, activities));
+    }
+
+    /// When the Thinker started thinking.
+    pub fn started(&self) -> Option<Duration> {
+        self.started
+    }
+
+    /// When `activity` last finished, if ever.
+    pub fn last(&self, activity: Activity) -> Option<Duration> {
+        self.last.get(&activity).copied()
+    }
+
+    /// Time between `activity` last finishing, or the Thinker starting if it
+    /// never did, and `now`.
+    pub fn since(&self, activity: Activity, now: Duration) -> Duration {
+        let last = self.last(activity).or(self.started).unwrap_or(now);
+        now.saturating_sub(last)
+    }
+
+    /// How many times `activity` finished at or after `since`.
+    pub fn count_since(&self, activity: Activity, since: Duration) -> usize {
+        let recent = self.completions.iter().rev();
+        recent
+            .take_while(|&&(at, _)| at >= since)
+            .filter(|(_, activities)| activities.contains(&activity))
+            .count()
+    }
+}
+
+/// Looks up the component types of Actions.
+#[derive(SystemParam)]
+pub(crate) struct ActionTypes<'w> {
+    entities: &'w Entities,
+    archetypes: &'w Archetypes,
+    components: &'w Components,
+}
+
+impl ActionTypes<'_> {
+    pub(crate) fn activities(&self, action: Entity, choice: Option<usize>) -> Vec<Activity> {
+        activities(
+            self.entities,
+            self.archetypes,
+            self.components,
+            action,
+            choice,
+        )
+    }
+}
+
+/// The [`Activity`]s `action` finishing counts as: the choice it was picked
+/// for, and the types of its components that every Action has.
+fn activities(
+    entities: &Entities,
+    archetypes: &Archetypes,
+    components: &Components,
+    action: Entity,
+    choice: Option<usize>,
+) -> Vec<Activity> {
+    let bookkeeping = action::bookkeeping();
+    let types = entities.get(action).into_iter().flat_map(|location| {
+        archetypes[location.archetype_id]
+            .components()
+            .filter_map(|id| components.get_info(id)?.type_id())
+            .filter(|type_id| !bookkeeping.contains(type_id))
+    });
+    choice
+        .map(Activity::Choice)
+        .into_iter()
+        .chain(types.map(Activity::Action))
+        .collect()
+}
+
+/// Hook that records finished Actions nested in Composite Actions when they
+/// get despawned, since Thinkers only see their outermost Action finish.
+pub(crate) fn record_nested_action(mut world: DeferredWorld, action: Entity, _: ComponentId) {
+    let nested = world.get::<Parent>(action).is_some();
+    let done = world
+        .get::<ActionState>(action)
+        .is_some_and(ActionState::is_done);
+    let actor = world.get::<Actor>(action).map(Actor::entity);
+    let thinker = actor.and_then(|actor| world.get::<HasThinker>(actor));
+    let Some(thinker) = thinker.map(HasThinker::entity).filter(|_| nested && done) else {
+        return;
+    };
+
+    let now = world
+        .get_resource::<Time>()
+        .map_or(Duration::ZERO, |time| time.elapsed());
+    let done = activities(
+        world.entities(),
+        world.archetypes(),
+        world.components(),
+        action,
+        None,
+    );
+    if let Some(mut thinker) = world.get_mut::<Thinker>(thinker) {
+        thinker.history.record(now, done);
+    }
+}
+
+/// Looks up the [`ThinkerHistory`] of Actors for Scorers.
+#[derive(SystemParam)]
+pub(crate) struct Histories<'w, 's> {
+    thinkers: Query<'w, 's, &'static Thinker>,
+    actors: Query<'w, 's, &'static HasThinker>,
+    states: Query<'w, 's, &'static ActionState>,
+    types: ActionTypes<'w>,
+}
+
+impl Histories<'_, '_> {
+    /// The [`ThinkerHistory`] of `actor`, along with the [`Activity`]s of its
+    /// current Action if it's done, but its Thinker hasn't noticed yet.
+    /// Otherwise Scorers would only see a finished Action one frame late,
+    /// after the Thinker already picked again.
+    fn get(&self, actor: Entity) -> Option<(&ThinkerHistory, Vec<Activity>)> {
+        let thinker = self.actors.get(actor).ok()?;
+        let thinker = self.thinkers.get(thinker.entity()).ok()?;
+        let pending = thinker
+            .current()
+            .filter(|action| {
+                self.states
+                    .get(action.entity())
+                    .is_ok_and(ActionState::is_done)
+            })
+            .map(|action| self.types.activities(action.entity(), thinker.winner()))
+            .unwrap_or_default();
+        Some((thinker.history(), pending))
+    }
+
+    /// Time between `activity` last finishing for `actor` and `now`. See
+    /// [`ThinkerHistory::since`].
+    pub(crate) fn since(
+        &self,
+        actor: Entity,
+        activity: Activity,
+        now: Duration,
+    ) -> Option<Duration> {
+        let (history, pending) = self.get(actor)?;
+        if pending.contains(&activity) {
+            Some(Duration::ZERO)
+        } else {
+            Some(history.since(activity, now))
+        }
+    }
+}
+
+/// Scorer that evaluates how many seconds it's been since the Actor last
+/// finished an [`Activity`], or since its Thinker started if it never did,
+/// with an [`Evaluator`].
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Stretch;
+/// # fn main() {
+/// // Stretch more urgently the longer it's been, up to a minute.
+/// let stiff = TimeSinceScorer::action::<Stretch>(Linear::new((0.0, 0.0), (60.0, 1.0)));
+/// ThinkerSpawner::highest(0.5).when(stiff, Stretch)
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Clone)]
+pub struct TimeSinceScorer {
+    activity: Activity,
+    evaluator: Arc<dyn Evaluator>,
+}
+
+impl TimeSinceScorer {
+    pub fn new(activity: Activity, evaluator: impl Evaluator + 'static) -> Self {
+        Self {
+            activity,
+            evaluator: Arc::new(evaluator),
+        }
+    }
+
+    /// Time since the Action picked for the choice with index `choice` last
+    /// finished.
+    pub fn choice(choice: usize, evaluator: impl Evaluator + 'static) -> Self {
+        Self::new(Activity::Choice(choice), evaluator)
+    }
+
+    /// Time since an Action with a `T` component last finished.
+    pub fn action<T: Component>(evaluator: impl Evaluator + 'static) -> Self {
+        Self::new(Activity::action::<T>(), evaluator)
+    }
+}
+
+impl ScorerSpawn for TimeSinceScorer {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn(self.clone())
+    }
+}
+
+pub(crate) fn time_since_scorer_system(
+    time: Option<Res<Time>>,
+    histories: Histories,
+    mut query: Query<(ScorerQuery, &TimeSinceScorer)>,
+) {
+    let Some(now) = time.map(|time| time.elapsed()) else {
+        return;
+    };
+    for (mut score, scorer) in query.iter_mut() {
+        let Some(since) = histories.since(score.actor(), scorer.activity, now) else {
+            continue;
+        };
+        score.set(scorer.evaluator.evaluate(since.as_secs_f32()));
+    }
+}
+
+/// Scorer that evaluates how many times the Actor finished an [`Activity`]
+/// in the last `window` seconds with an [`Evaluator`]. Only the most recent
+/// [`ThinkerHistory::CAPACITY`] completions are counted.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct Hungry;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct EatBread;
+/// # fn main() {
+/// // Get bored of bread after eating it three times in five minutes.
+/// let bored = BoredomScorer::action::<EatBread>(300.0, Linear::new((0.0, 1.0), (3.0, 0.0)));
+/// ThinkerSpawner::highest(0.5).when(ProductOfScorers::build(0.0, (Hungry, bored)), EatBread)
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Clone)]
+pub struct BoredomScorer {
+    activity: Activity,
+    window: Duration,
+    evaluator: Arc<dyn Evaluator>,
+}
+
+impl BoredomScorer {
+    /// Negative windows count as zero seconds.
+    pub fn new(activity: Activity, window: f32, evaluator: impl Evaluator + 'static) -> Self {
+        let window = Duration::try_from_secs_f32(window.max(0.0)).unwrap_or(Duration::MAX);
+        Self {
+            activity,
+            window,
+            evaluator: Arc::new(evaluator),
+        }
+    }
+
+    /// Times the Action picked for the choice with index `choice` finished.
+    pub fn choice(choice: usize, window: f32, evaluator: impl Evaluator + 'static) -> Self {
+        Self::new(Activity::Choice(choice), window, evaluator)
+    }
+
+    /// Times an Action with a `T` component finished.
+    pub fn action<T: Component>(window: f32, evaluator: impl Evaluator + 'static) -> Self {
+        Self::new(Activity::action::<T>(), window, evaluator)
+    }
+}
+
+impl ScorerSpawn for BoredomScorer {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn(self.clone())
+    }
+}
+
+pub(crate) fn boredom_scorer_system(
+    time: Option<Res<Time>>,
+    histories: Histories,
+    mut query: Query<(ScorerQuery, &BoredomScorer)>,
+) {
+    let Some(now) = time.map(|time| time.elapsed()) else {
+        return;
+    };
+    for (mut score, scorer) in query.iter_mut() {
+        let Some((history, pending)) = histories.get(score.actor()) else {
+            continue;
+        };
+        let since = now.saturating_sub(scorer.window);
+        let count = history.count_since(scorer.activity, since);
+        let count = count + usize::from(pending.contains(&scorer.activity));
+        score.set(scorer.evaluator.evaluate(count as f32));
+    }
+}
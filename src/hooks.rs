@@ -0,0 +1,146 @@
+//! Trait-based alternative to writing Action systems by hand.
+
+use crate::action::{ActionQuery, ActionQueryItem, ActionState};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    system::{Query, StaticSystemParam, SystemParam, SystemParamItem},
+};
+
+/// What an Action with [`ActionHooks`] should do after a hook ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionStatus {
+    /// Keep going. The hook gets called again next frame.
+    Running,
+    /// End in [`ActionState::Success`].
+    Success,
+    /// End in [`ActionState::Failure`].
+    Failure,
+}
+
+/// Lifecycle callbacks for an Action component, as an alternative to matching
+/// on [`ActionState`] in a hand-written Action system. Register the Action
+/// with [`BigBrainApp::add_action_hooks`](crate::BigBrainApp::add_action_hooks),
+/// and Big Brain takes care of scheduling a system that calls the hooks in
+/// [`BigBrainSet::Actions`](crate::BigBrainSet::Actions).
+///
+/// * [`ActionHooks::on_start`] gets called once, on the first frame the Action
+///   is executing, right before the first [`ActionHooks::on_tick`].
+/// * [`ActionHooks::on_tick`] gets called every frame while the Action is
+///   executing.
+/// * [`ActionHooks::on_cancel`] gets called every frame while the Action is
+///   cancelled, until it returns something other than
+///   [`ActionStatus::Running`]. It might get called without
+///   [`ActionHooks::on_start`], for Actions that got cancelled before they
+///   got to run.
+/// * [`ActionHooks::on_finish`] gets called once the Action ends in
+///   [`ActionState::Success`] or [`ActionState::Failure`].
+///
+/// All hooks get the Action itself, for looking up its actor or reporting
+/// progress and outcomes, and the system parameters in [`ActionHooks::Param`].
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::{ecs::system::SystemParamItem, prelude::*};
+/// # use big_brain::*;
+/// #[derive(Component)]
+/// struct Thirst(f32);
+///
+/// #[derive(Clone, Component, ActionSpawn)]
+/// struct Drink;
+///
+/// impl ActionHooks for Drink {
+///     type Param = Query<'static, 'static, &'static mut Thirst>;
+///
+///     fn on_tick(
+///         &mut self,
+///         action: &mut ActionQueryItem,
+///         thirsts: &mut SystemParamItem<Self::Param>,
+///     ) -> ActionStatus {
+///         let mut thirst = thirsts.get_mut(action.actor()).unwrap();
+///         thirst.0 = (thirst.0 - 10.0).max(0.0);
+///         if thirst.0 == 0.0 {
+///             ActionStatus::Success
+///         } else {
+///             ActionStatus::Running
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((
+///         MinimalPlugins,
+///         AssetPlugin::default(),
+///         BigBrainPlugin::new(Update, Update, PostUpdate, Last),
+///     ))
+///     .add_action_hooks::<Drink>();
+/// # }
+/// ```
+pub trait ActionHooks: Component + Sized {
+    /// System parameters the hooks have access to. Use `()` if they don't
+    /// need any.
+    type Param: SystemParam + 'static;
+
+    /// Called when the Action starts executing.
+    fn on_start(
+        &mut self,
+        _action: &mut ActionQueryItem,
+        _param: &mut SystemParamItem<Self::Param>,
+    ) {
+    }
+
+    /// Called every frame while the Action is executing.
+    fn on_tick(
+        &mut self,
+        action: &mut ActionQueryItem,
+        param: &mut SystemParamItem<Self::Param>,
+    ) -> ActionStatus;
+
+    /// Called every frame while the Action is cancelled. Ends in
+    /// [`ActionState::Failure`] right away by default.
+    fn on_cancel(
+        &mut self,
+        _action: &mut ActionQueryItem,
+        _param: &mut SystemParamItem<Self::Param>,
+    ) -> ActionStatus {
+        ActionStatus::Failure
+    }
+
+    /// Called once the Action is done. Look at `action.state()` for the
+    /// result.
+    fn on_finish(
+        &mut self,
+        _action: &mut ActionQueryItem,
+        _param: &mut SystemParamItem<Self::Param>,
+    ) {
+    }
+}
+
+/// System that calls the [`ActionHooks`] of any existing `T` Actions.
+pub fn action_hooks_system<T: ActionHooks>(
+    mut query: Query<(ActionQuery, &mut T)>,
+    param: StaticSystemParam<T::Param>,
+) {
+    let mut param = param.into_inner();
+    for (mut action, mut hooks) in query.iter_mut() {
+        let status = match action.state() {
+            ActionState::Executing => {
+                if hooks.is_added() {
+                    hooks.on_start(&mut action, &mut param);
+                }
+                hooks.on_tick(&mut action, &mut param)
+            }
+            ActionState::Cancelled => hooks.on_cancel(&mut action, &mut param),
+            ActionState::Success | ActionState::Failure => continue,
+        };
+
+        match status {
+            ActionStatus::Running => continue,
+            ActionStatus::Success => action.success(),
+            ActionStatus::Failure => action.failure(),
+        }
+        hooks.on_finish(&mut action, &mut param);
+    }
+}
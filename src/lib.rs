@@ -170,32 +170,61 @@
 //! This project is licensed under [the Apache-2.0 License](LICENSE.md).
 
 mod action;
+mod app;
+mod closure;
+mod consideration;
 mod evaluator;
+mod field;
+mod guard;
+mod history;
+mod hooks;
+mod matches;
 mod measures;
 mod pickers;
 mod scorer;
+mod selector;
 mod sequence;
+mod state_machine;
 mod thinker;
+mod wait;
 
 pub use big_brain_derive::{ActionSpawn, ScorerSpawn};
 
 pub use crate::{
-    action::{Action, ActionCommands, ActionQuery, ActionSpawn, ActionState},
+    action::{
+        Action, ActionCommands, ActionOutcome, ActionProgress, ActionQuery, ActionQueryItem,
+        ActionSpawn, ActionState, CancelReason, Cancellation,
+    },
+    app::BigBrainApp,
+    closure::{FnAction, FnScorer},
+    consideration::{Consideration, ConsiderationInput},
     evaluator::{EvaluatingScorer, Evaluator, FnEvaluator, Linear, Power, Sigmoid},
+    field::FieldScorer,
+    guard::{Guard, GuardSpawner},
+    history::{Activity, BoredomScorer, ThinkerHistory, TimeSinceScorer},
+    hooks::{ActionHooks, ActionStatus},
+    matches::MatchScorer,
     measures::{Measure, MeasuredScorer, WeightedScore},
     pickers::{FirstToScore, Highest, Picker},
     scorer::{
-        AllOrNothing, CompensatedProductOfScorers, FixedScorer, ProductOfScorers, Score, Scorer,
-        ScorerCommands, ScorerQuery, ScorerSpawn, ScorerSpawner, SumOfScorers, WinningScorer,
+        AllOrNothing, CompensatedProductOfScorers, CompositeScorer, FixedScorer, LatchScorer,
+        ProductOfScorers, Score, ScorePolicy, Scorer, ScorerCommands, ScorerQuery, ScorerSpawn,
+        ScorerSpawner, SmoothedScorer, Smoothing, SumOfScorers, WinningScorer,
+    },
+    selector::{Selector, SelectorSpawner},
+    sequence::{
+        Finally, FinallySpawner, Sequence, SequenceMode, SequenceSpawner, StepFailurePolicy,
+        StepSummary,
     },
-    sequence::{Sequence, SequenceMode, SequenceSpawner},
-    thinker::{Actor, HandleThinkerSpawner, HasThinker, Thinker, ThinkerSpawner},
+    state_machine::{StateMachine, StateMachineSpawner},
+    thinker::{ActionFinished, Actor, HandleThinkerSpawner, HasThinker, Thinker, ThinkerSpawner},
+    wait::{Wait, Waiting},
 };
 
 use bevy_app::{App, Plugin};
 use bevy_asset::AssetApp;
 use bevy_ecs::intern::Interned;
-use bevy_ecs::schedule::{IntoSystemConfigs, ScheduleLabel, SystemSet};
+use bevy_ecs::schedule::{IntoSystemConfigs, IntoSystemSetConfigs, ScheduleLabel, SystemSet};
 
 /// Core [`Plugin`] for Big Brain behavior. Required for any of the
 /// [`Thinker`]-related magic to work.
@@ -238,40 +267,88 @@ impl BigBrainPlugin {
 
 impl Plugin for BigBrainPlugin {
     fn build(&self, app: &mut App) {
-        app.init_asset::<crate::thinker::ThinkerSpawner>()
-            .configure_sets(self.scorers.intern(), BigBrainSet::Scorers)
-            .configure_sets(self.actions.intern(), BigBrainSet::Actions)
-            .configure_sets(self.sequence.intern(), BigBrainSet::Sequence)
-            .configure_sets(self.thinker.intern(), BigBrainSet::Thinker)
-            .add_systems(
-                self.scorers.intern(),
-                (
-                    crate::scorer::idle_scorer_system,
-                    crate::scorer::fixed_scorer_system,
-                    crate::measures::measured_scorers_system,
-                    crate::scorer::all_or_nothing_system,
-                    crate::scorer::sum_of_scorers_system,
-                    crate::scorer::product_of_scorers_system,
-                    crate::scorer::compensated_product_of_scorers_system,
-                    crate::scorer::winning_scorer_system,
-                    crate::evaluator::evaluating_scorer_system,
-                )
-                    .in_set(BigBrainSet::Scorers),
+        app.insert_resource(crate::app::BigBrainSchedules {
+            scorers: self.scorers,
+            actions: self.actions,
+        })
+        .insert_resource(crate::app::Registered::builtin())
+        .init_resource::<ScorePolicy>()
+        .register_composite_scorer::<AllOrNothing>()
+        .register_composite_scorer::<SumOfScorers>()
+        .register_composite_scorer::<ProductOfScorers>()
+        .register_composite_scorer::<CompensatedProductOfScorers>()
+        .register_composite_scorer::<WinningScorer>()
+        .register_composite_scorer::<MeasuredScorer>()
+        .register_composite_scorer::<EvaluatingScorer>()
+        .init_asset::<crate::thinker::ThinkerSpawner>()
+        .add_event::<crate::thinker::ActionFinished>()
+        .configure_sets(self.scorers.intern(), BigBrainSet::Scorers)
+        .configure_sets(self.actions.intern(), BigBrainSet::Actions)
+        .configure_sets(self.sequence.intern(), BigBrainSet::Sequence)
+        .configure_sets(self.thinker.intern(), BigBrainSet::Thinker)
+        .configure_sets(
+            self.scorers.intern(),
+            BigBrainSet::Thinker.after(BigBrainSet::Scorers),
+        )
+        .add_systems(
+            self.scorers.intern(),
+            (
+                crate::scorer::idle_scorer_system,
+                crate::scorer::fixed_scorer_system,
+                crate::closure::fn_scorer_system,
+                crate::field::field_scorer_system,
+                crate::consideration::consideration_system,
+                crate::matches::match_scorer_system,
+                crate::history::time_since_scorer_system,
+                crate::history::boredom_scorer_system,
+            )
+                .in_set(BigBrainSet::Scorers),
+        )
+        .add_systems(
+            self.actions,
+            (
+                crate::wait::wait_action_system,
+                crate::closure::fn_action_system,
             )
-            .add_systems(
-                self.sequence,
-                crate::sequence::sequence_system.in_set(BigBrainSet::Sequence),
+                .in_set(BigBrainSet::Actions),
+        )
+        .add_systems(
+            self.sequence,
+            (
+                crate::sequence::sequence_system,
+                crate::sequence::finally_system,
+                crate::guard::guard_system,
+                crate::selector::selector_system,
+                crate::state_machine::state_machine_system,
             )
-            .add_systems(
-                self.scorers.intern(),
-                (
-                    crate::thinker::thinker_maintain_system,
-                    crate::thinker::thinker_system,
-                    crate::thinker::actor_gone_cleanup,
-                )
-                    .chain()
-                    .in_set(BigBrainSet::Thinker),
-            );
+                .in_set(BigBrainSet::Sequence),
+        )
+        .add_systems(
+            self.scorers.intern(),
+            (
+                crate::scorer::score_policy_system,
+                crate::scorer::composite_scorers_system,
+                crate::thinker::thinker_maintain_system,
+                crate::thinker::thinker_system,
+                crate::thinker::actor_gone_cleanup,
+            )
+                .chain()
+                .in_set(BigBrainSet::Thinker),
+        );
+
+        app.world_mut()
+            .register_component_hooks::<ActionState>()
+            .on_remove(crate::history::record_nested_action);
+
+        // Built-in composites that keep state between frames.
+        crate::scorer::CompositeScorers::register_with::<SmoothedScorer>(
+            app.world_mut(),
+            crate::scorer::combine_smoothed,
+        );
+        crate::scorer::CompositeScorers::register_with::<LatchScorer>(
+            app.world_mut(),
+            crate::scorer::combine_latch,
+        );
     }
 }
 
@@ -0,0 +1,136 @@
+//! Scorers for conditions that are just about which components the Actor
+//! has, like "is stunned" or "isn't carrying anything".
+
+use crate::{
+    scorer::{Score, Scorer, ScorerCommands, ScorerSpawn},
+    thinker::Actor,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{QueryFilter, QueryState, With, Without},
+    system::Local,
+    world::World,
+};
+use bevy_utils::HashMap;
+use std::any::{Any, TypeId};
+
+/// [`QueryState`]s of every filter used by a [`MatchScorer`], by filter type.
+type Filters = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+type MatchFn = fn(&mut World, &mut Filters, Entity) -> bool;
+
+fn matches<F: QueryFilter + 'static>(
+    world: &mut World,
+    filters: &mut Filters,
+    actor: Entity,
+) -> bool {
+    let state = filters
+        .entry(TypeId::of::<F>())
+        .or_insert_with(|| Box::new(QueryState::<(), F>::new(world)));
+    let state = state
+        .downcast_mut::<QueryState<(), F>>()
+        .expect("filters are keyed by their type");
+    state.get(world, actor).is_ok()
+}
+
+/// Scorer that Scores `1.0` when the Actor matches a [`QueryFilter`], and
+/// `0.0` when it doesn't. Both values are configurable with
+/// [`MatchScorer::values`].
+///
+/// Big Brain evaluates these by itself, so there's no system to write or
+/// register. Use them as gates with [`AllOrNothing`](crate::AllOrNothing) or
+/// [`ProductOfScorers`](crate::ProductOfScorers).
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Component)]
+/// # struct Stunned;
+/// # #[derive(Component)]
+/// # struct Carrying;
+/// # #[derive(Component)]
+/// # struct Storage;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct NearStorage;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Store;
+/// # fn main() {
+/// let can_store = AllOrNothing::build(
+///     0.5,
+///     (
+///         MatchScorer::lacks::<Stunned>(),
+///         MatchScorer::filter::<(With<Carrying>, Without<Storage>)>(),
+///         NearStorage,
+///     ),
+/// );
+/// ThinkerSpawner::highest(0.5).when(can_store, Store)
+/// # ;
+/// # }
+/// ```
+#[derive(Clone, Copy, Component)]
+pub struct MatchScorer {
+    matches: MatchFn,
+    matched: f32,
+    unmatched: f32,
+}
+
+impl MatchScorer {
+    /// Matches Actors that match the [`QueryFilter`] `F`.
+    pub fn filter<F: QueryFilter + 'static>() -> Self {
+        Self {
+            matches: matches::<F>,
+            matched: 1.0,
+            unmatched: 0.0,
+        }
+    }
+
+    /// Matches Actors with a `T` component.
+    pub fn has<T: Component>() -> Self {
+        Self::filter::<With<T>>()
+    }
+
+    /// Matches Actors without a `T` component.
+    pub fn lacks<T: Component>() -> Self {
+        Self::filter::<Without<T>>()
+    }
+
+    /// Scores `matched` when the Actor matches, and `unmatched` when it
+    /// doesn't, instead of `1.0` and `0.0`.
+    pub fn values(mut self, matched: f32, unmatched: f32) -> Self {
+        self.matched = matched;
+        self.unmatched = unmatched;
+        self
+    }
+}
+
+impl ScorerSpawn for MatchScorer {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn(*self)
+    }
+}
+
+pub(crate) fn match_scorer_system(
+    world: &mut World,
+    query: &mut QueryState<(Entity, &Actor, &MatchScorer)>,
+    mut scorers: Local<Vec<(Entity, Entity, MatchScorer)>>,
+    mut filters: Local<Filters>,
+) {
+    scorers.extend(
+        query
+            .iter(world)
+            .map(|(entity, actor, &scorer)| (entity, actor.entity(), scorer)),
+    );
+    for (entity, actor, scorer) in scorers.drain(..) {
+        let value = if (scorer.matches)(world, &mut filters, actor) {
+            scorer.matched
+        } else {
+            scorer.unmatched
+        };
+        if let Some(mut score) = world.get_mut::<Score>(entity) {
+            score.set(value);
+        }
+    }
+}
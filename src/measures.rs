@@ -2,13 +2,8 @@
 //!   [Measures](https://en.wikipedia.org/wiki/Measure_(mathematics)) used to
 //!  * weight score.
 
-use crate::scorer::{Score, Scorer, ScorerCommands, ScorerSpawn};
-use bevy_ecs::{
-    component::Component,
-    entity::Entity,
-    system::{Local, Query},
-};
-use bevy_hierarchy::Children;
+use crate::scorer::{CompositeScorer, Scorer, ScorerCommands, ScorerSpawn};
+use bevy_ecs::component::Component;
 use bevy_utils::all_tuples;
 use std::sync::Arc;
 
@@ -161,25 +156,19 @@ impl ScorerSpawn for MeasuredScorerSpawner {
     }
 }
 
-pub fn measured_scorers_system(
-    mut cache: Local<Vec<WeightedScore>>,
-    query: Query<(Entity, &MeasuredScorer, &Children)>,
-    mut scores: Query<&mut Score>,
-) {
-    for (this_entity, this, children) in query.iter() {
-        let weights = this.weights.iter().copied();
-        let scorers = children.iter().map(|&e| scores.get(e).cloned().unwrap().0);
-        let weighted = scorers.zip(weights);
-
-        cache.extend(weighted.map(|(score, weight)| WeightedScore { score, weight }));
-
-        let score = {
-            let score = (this.measure)(&cache);
-            let filter = score >= this.threshold;
-            (if filter { score } else { 0.0 }).clamp(0.0, 1.0)
-        };
-
-        scores.get_mut(this_entity).unwrap().set(score);
-        cache.clear();
+impl CompositeScorer for MeasuredScorer {
+    fn combine(&self, children: &[f32]) -> f32 {
+        let weights = self.weights.iter().copied();
+        let weighted = children.iter().copied().zip(weights);
+        let weighted = weighted
+            .map(|(score, weight)| WeightedScore { score, weight })
+            .collect::<Vec<_>>();
+
+        let score = (self.measure)(&weighted);
+        if score >= self.threshold {
+            score.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
     }
 }
@@ -2,18 +2,22 @@
 //! range of 0.0..=1.0. This module includes the ScorerBuilder trait and some
 //! built-in Composite Scorers.
 
-use crate::thinker::Actor;
+use crate::{action::insert_from_actor, thinker::Actor};
 use bevy_ecs::{
+    archetype::Archetypes,
     bundle::Bundle,
-    component::Component,
-    entity::Entity,
-    query::{QueryData, With},
-    system::{Commands, Query},
+    component::{Component, ComponentId, Components},
+    entity::{Entities, Entity},
+    query::{Changed, QueryData, With, Without},
+    system::{Commands, Local, Query, Res, Resource, SystemParam},
+    world::{EntityMut, EntityRef, World},
 };
-use bevy_hierarchy::{AddChild, Children};
+use bevy_hierarchy::{AddChild, Children, Parent};
+use bevy_log as log;
 use bevy_reflect::Reflect;
+use bevy_time::Time;
 use bevy_utils::all_tuples;
-use std::sync::Arc;
+use std::{any::TypeId, sync::Arc};
 
 pub struct ScorerCommands<'w, 's, 'a> {
     cmd: &'a mut Commands<'w, 's>,
@@ -32,6 +36,21 @@ impl<'w, 's, 'a> ScorerCommands<'w, 's, 'a> {
         Scorer(self.cmd.spawn(bundle).id())
     }
 
+    /// Spawns a Scorer whose components are built from the actor's current
+    /// state. `init` gets read-only access to the actor once the commands are
+    /// applied, before any Scorer system gets to see the Scorer.
+    #[inline]
+    pub fn spawn_with<B: Bundle>(
+        &mut self,
+        init: impl FnOnce(EntityRef) -> B + Send + 'static,
+    ) -> Scorer {
+        let scorer = self.spawn(());
+        let actor = self.actor.entity();
+        self.cmd
+            .queue(move |world: &mut World| insert_from_actor(world, actor, scorer.0, init));
+        scorer
+    }
+
     #[inline]
     pub fn push_child(&mut self, Scorer(parent): Scorer, builder: &dyn ScorerSpawn) {
         let Scorer(child) = builder.spawn(ScorerCommands::new(self.cmd, self.actor));
@@ -96,19 +115,17 @@ impl Score {
         self.0
     }
 
-    /// Set the `Score`'s value.
-    ///
-    /// ### Panics
-    ///
-    /// Panics if `value` isn't within `0.0..=1.0`.
+    /// Set the `Score`'s value. Values that aren't within `0.0..=1.0` (or
+    /// NaN) are dealt with according to the [`ScorePolicy`] once all the
+    /// Scorers ran, which panics by default in debug builds.
     pub fn set(&mut self, value: f32) {
-        if !(0.0..=1.0).contains(&value) {
-            panic!("Score value must be between 0.0 and 1.0");
-        }
         self.0 = value;
     }
 
-    /// Set the `Score`'s value. Allows values outside the range `0.0..=1.0`
+    /// Set the `Score`'s value, for Scorers that produce values outside the
+    /// range `0.0..=1.0` on purpose. The value still goes through the
+    /// [`ScorePolicy`], so such Scorers should be spawned with a
+    /// [`ScorePolicy::Unchecked`] component to opt out of it.
     /// WARNING: `Scorer`s are significantly harder to compose when there
     /// isn't a set scale. Avoid using unless it's not feasible to rescale
     /// and use `set` instead.
@@ -117,6 +134,146 @@ impl Score {
     }
 }
 
+/// Marks Scorers that already logged a warning, so they don't log another
+/// one. It goes away along with the Scorer.
+#[derive(Component)]
+pub(crate) struct Warned;
+
+/// What to do with [`Score`]s outside of `0.0..=1.0`, or NaN.
+///
+/// The policy applies to every Scorer, including the built-in Composite
+/// Scorers. Insert it as a resource to change the global policy, or as a
+/// component on a Scorer to override it for that Scorer only. Diagnostics
+/// name the Scorer entity and its component types.
+///
+/// Defaults to [`ScorePolicy::Panic`] in debug builds, and
+/// [`ScorePolicy::ClampAndWarnOnce`] otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Resource, Reflect)]
+pub enum ScorePolicy {
+    /// Panic on the first invalid [`Score`].
+    Panic,
+    /// Clamp [`Score`]s to `0.0..=1.0`, and turn NaN into `0.0`.
+    Clamp,
+    /// Same as [`ScorePolicy::Clamp`], but log a warning the first time a
+    /// Scorer produces an invalid [`Score`].
+    ClampAndWarnOnce,
+    /// Turn NaN into `0.0`, but panic on other invalid [`Score`]s.
+    NanAsZero,
+    /// Leave [`Score`]s alone. Mostly useful as a component on Scorers that
+    /// use [`Score::set_unchecked`].
+    Unchecked,
+}
+
+impl Default for ScorePolicy {
+    fn default() -> Self {
+        if cfg!(debug_assertions) {
+            Self::Panic
+        } else {
+            Self::ClampAndWarnOnce
+        }
+    }
+}
+
+impl ScorePolicy {
+    /// Applies the policy to the `value` of `scorer`. `describe` names the
+    /// Scorer's components, and is only called for diagnostics. `warned`
+    /// tells whether the Scorer already logged a warning, and is set once it
+    /// does.
+    pub(crate) fn apply(
+        self,
+        value: f32,
+        scorer: Entity,
+        describe: impl FnOnce() -> String,
+        warned: &mut bool,
+    ) -> f32 {
+        if (0.0..=1.0).contains(&value) || self == Self::Unchecked {
+            return value;
+        }
+
+        let clamped = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+        match self {
+            Self::Clamp => clamped,
+            Self::NanAsZero if value.is_nan() => clamped,
+            Self::ClampAndWarnOnce => {
+                if !*warned {
+                    *warned = true;
+                    log::warn!(
+                        "Scorer {:?} ({}) produced Score {}, which isn't within 0.0..=1.0",
+                        scorer,
+                        describe(),
+                        value
+                    );
+                }
+                clamped
+            }
+            Self::Panic | Self::NanAsZero | Self::Unchecked => panic!(
+                "Scorer {:?} ({}) produced Score {}, which isn't within 0.0..=1.0",
+                scorer,
+                describe(),
+                value
+            ),
+        }
+    }
+}
+
+/// Names the components of `entity` that aren't Big Brain bookkeeping.
+pub(crate) fn describe_scorer(
+    entities: &Entities,
+    archetypes: &Archetypes,
+    components: &Components,
+    entity: Entity,
+) -> String {
+    let Some(location) = entities.get(entity) else {
+        return String::from("despawned");
+    };
+    let bookkeeping = [
+        TypeId::of::<Score>(),
+        TypeId::of::<Actor>(),
+        TypeId::of::<ScorePolicy>(),
+        TypeId::of::<Warned>(),
+        TypeId::of::<Parent>(),
+        TypeId::of::<Children>(),
+    ];
+
+    archetypes[location.archetype_id]
+        .components()
+        .filter_map(|id| components.get_info(id))
+        .filter(|info| info.type_id().is_none_or(|id| !bookkeeping.contains(&id)))
+        .map(|info| info.name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// System that applies the [`ScorePolicy`] to every [`Score`] set in
+/// [`BigBrainSet::Scorers`](crate::BigBrainSet::Scorers).
+pub(crate) fn score_policy_system(
+    policy: Res<ScorePolicy>,
+    mut scores: Query<(Entity, &mut Score, Option<&ScorePolicy>), Changed<Score>>,
+    warned: Query<(), With<Warned>>,
+    entities: &Entities,
+    archetypes: &Archetypes,
+    components: &Components,
+    mut commands: Commands,
+) {
+    for (entity, mut score, override_policy) in scores.iter_mut() {
+        let policy = override_policy.copied().unwrap_or(*policy);
+        let describe = || describe_scorer(entities, archetypes, components, entity);
+        let already_warned = warned.contains(entity);
+        let mut now_warned = already_warned;
+        let value = policy.apply(score.get(), entity, describe, &mut now_warned);
+        if now_warned && !already_warned {
+            commands.entity(entity).insert(Warned);
+        }
+        if value != score.get() {
+            score.0 = value;
+        }
+    }
+}
+
 /// Trait that must be defined by types in order to be `ScorerBuilder`s.
 /// `ScorerBuilder`s' job is to spawn new `Scorer` entities. In general, most
 /// of this is already done for you, and the only method you really have to
@@ -196,6 +353,189 @@ pub fn fixed_scorer_system(mut query: Query<(&mut Score, &FixedScorer)>) {
     }
 }
 
+/// Composite Scorers combine the [`Score`]s of their child Scorers into a
+/// single [`Score`]. Implement this for a component to write your own
+/// composites, and register it with
+/// [`BigBrainApp::register_composite_scorer`](crate::BigBrainApp::register_composite_scorer).
+/// Big Brain takes care of looking up the child [`Score`]s and writing the
+/// combined one.
+///
+/// `combine` gets the child [`Score`]s in the order the children were added.
+/// Its result goes through the [`ScorePolicy`], just like any other
+/// [`Score`], so clamp it yourself if going out of range is expected.
+///
+/// Composite Scorers are evaluated after all the systems in
+/// [`BigBrainSet::Scorers`](crate::BigBrainSet::Scorers), right before the
+/// Thinkers pick their Actions, from the most deeply nested composite up. That
+/// way, composites always combine the Scores of the current frame, no matter
+/// how deeply they're nested.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// /// Composite Scorer that returns the lowest of its child Scores.
+/// #[derive(Clone, Component)]
+/// struct MinOfScorers;
+///
+/// impl CompositeScorer for MinOfScorers {
+///     fn combine(&self, children: &[f32]) -> f32 {
+///         children.iter().copied().fold(1.0, f32::min)
+///     }
+/// }
+///
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct MyOtherScorer;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct MyAction;
+/// # fn main() {
+/// App::new()
+///     .add_plugins((
+///         MinimalPlugins,
+///         AssetPlugin::default(),
+///         BigBrainPlugin::new(Update, Update, PostUpdate, Last),
+///     ))
+///     .register_composite_scorer::<MinOfScorers>();
+///
+/// ThinkerSpawner::highest(0.0)
+///     .when(ScorerSpawner::new(MinOfScorers, (MyScorer, MyOtherScorer)), MyAction)
+/// # ;
+/// # }
+/// ```
+pub trait CompositeScorer: Component {
+    /// Combines the child [`Score`]s into the [`Score`] of this Scorer.
+    fn combine(&self, children: &[f32]) -> f32;
+}
+
+/// Combines the child Scores of a composite Scorer of a specific type. Gets
+/// the composite's entity and the seconds since the last frame, so that
+/// built-in composites can keep state between frames.
+type CombineFn = fn(&mut EntityMut, &[f32], f32) -> f32;
+
+fn combine<T: CompositeScorer>(this: &mut EntityMut, children: &[f32], _: f32) -> f32 {
+    this.get::<T>().map_or(0.0, |this| this.combine(children))
+}
+
+/// Registered [`CompositeScorer`] component types.
+#[derive(Default, Resource)]
+pub(crate) struct CompositeScorers(Vec<(ComponentId, CombineFn)>);
+
+impl CompositeScorers {
+    pub(crate) fn register<T: CompositeScorer>(world: &mut World) {
+        Self::register_with::<T>(world, combine::<T>);
+    }
+
+    /// Registers a composite Scorer with a custom [`CombineFn`].
+    pub(crate) fn register_with<T: Component>(world: &mut World, combine: CombineFn) {
+        let id = world.register_component::<T>();
+        let mut composites = world.get_resource_or_init::<CompositeScorers>();
+        if composites.0.iter().all(|&(other, _)| other != id) {
+            composites.0.push((id, combine));
+        }
+    }
+}
+
+/// Everything [`composite_scorers_system`] needs to evaluate a composite.
+#[derive(SystemParam)]
+pub(crate) struct CompositeParams<'w, 's> {
+    combines: Res<'w, CompositeScorers>,
+    policy: Res<'w, ScorePolicy>,
+    time: Option<Res<'w, Time>>,
+    /// Composite Scorers, and any other Scorer with children.
+    scorers: Query<'w, 's, EntityMut<'static>, (With<Score>, With<Children>)>,
+    leaves: Query<'w, 's, &'static Score, Without<Children>>,
+    entities: &'w Entities,
+    archetypes: &'w Archetypes,
+    components: &'w Components,
+    commands: Commands<'w, 's>,
+}
+
+impl CompositeParams<'_, '_> {
+    /// Evaluates the nested composites of `entity` first, then `entity`
+    /// itself, and returns its [`Score`]. `cache` is used as a stack for the
+    /// child Scores.
+    fn evaluate(&mut self, entity: Entity, cache: &mut Vec<f32>) -> f32 {
+        let start = cache.len();
+        // Children are looked up one at a time, since evaluating the nested
+        // ones needs the query.
+        let mut index = 0;
+        while let Some(child) = self.scorers.get(entity).ok().and_then(|this| {
+            let children = this.get::<Children>()?;
+            children.get(index).copied()
+        }) {
+            index += 1;
+            let score = if self.scorers.contains(child) {
+                self.evaluate(child, cache)
+            } else if let Ok(score) = self.leaves.get(child) {
+                score.get()
+            } else {
+                // Children without a Score aren't Scorers, so they don't count.
+                continue;
+            };
+            cache.push(score);
+        }
+
+        let Ok(mut this) = self.scorers.get_mut(entity) else {
+            return 0.0;
+        };
+        let combine = self
+            .combines
+            .0
+            .iter()
+            .find(|&&(id, _)| this.contains_id(id));
+        let score = if let Some(&(_, combine)) = combine {
+            let dt = self.time.as_ref().map_or(0.0, |time| time.delta_secs());
+            let score = combine(&mut this, &cache[start..], dt);
+            let policy = this.get::<ScorePolicy>().copied().unwrap_or(*self.policy);
+            let describe =
+                || describe_scorer(self.entities, self.archetypes, self.components, entity);
+            let already_warned = this.contains::<Warned>();
+            let mut now_warned = already_warned;
+            let score = policy.apply(score, entity, describe, &mut now_warned);
+            if now_warned && !already_warned {
+                self.commands.entity(entity).insert(Warned);
+            }
+            if let Some(mut this_score) = this.get_mut::<Score>() {
+                this_score.set(score);
+            }
+            score
+        } else {
+            // Not a composite, so some other system takes care of its Score.
+            this.get::<Score>().map_or(0.0, Score::get)
+        };
+        cache.truncate(start);
+        score
+    }
+}
+
+/// System that updates the [`Score`] of every [`CompositeScorer`], bottom-up:
+/// starting from the outermost Scorers with children, nested composites are
+/// evaluated before the composites they're nested in, so every composite sees
+/// the current Scores of its children.
+pub(crate) fn composite_scorers_system(
+    mut params: CompositeParams,
+    mut roots: Local<Vec<Entity>>,
+    mut cache: Local<Vec<f32>>,
+) {
+    let scorers = &params.scorers;
+    roots.extend(
+        scorers
+            .iter()
+            .filter(|scorer| {
+                let parent = scorer.get::<Parent>();
+                parent.is_none_or(|parent| !scorers.contains(parent.get()))
+            })
+            .map(|scorer| scorer.id()),
+    );
+
+    for root in roots.drain(..) {
+        params.evaluate(root, &mut cache);
+    }
+}
+
 /// Composite Scorer that takes any number of other Scorers and returns the
 /// sum of their [`Score`] values if each _individual_ [`Score`] is at or
 /// above the configured `threshold`.
@@ -228,22 +568,13 @@ impl AllOrNothing {
     }
 }
 
-pub fn all_or_nothing_system(
-    query: Query<(Entity, &AllOrNothing, &Children)>,
-    mut scores: Query<&mut Score>,
-) {
-    for (aon_ent, AllOrNothing { threshold }, scorers) in query.iter() {
-        let mut sum = 0.0;
-        for &child in scorers.iter() {
-            let score = scores.get(child).unwrap();
-            if score.0 < *threshold {
-                sum = 0.0;
-                break;
-            } else {
-                sum += score.0;
-            }
+impl CompositeScorer for AllOrNothing {
+    fn combine(&self, children: &[f32]) -> f32 {
+        if children.iter().all(|&score| score >= self.threshold) {
+            children.iter().sum::<f32>().clamp(0.0, 1.0)
+        } else {
+            0.0
         }
-        scores.get_mut(aon_ent).unwrap().set(sum.clamp(0.0, 1.0));
     }
 }
 
@@ -277,20 +608,14 @@ impl SumOfScorers {
     }
 }
 
-pub fn sum_of_scorers_system(
-    query: Query<(Entity, &SumOfScorers, &Children)>,
-    mut scores: Query<&mut Score>,
-) {
-    for (sos_ent, SumOfScorers { threshold }, scorers) in query.iter() {
-        let mut sum = 0.0;
-        for &child in scorers.iter() {
-            let score = scores.get(child).unwrap();
-            sum += score.0;
-        }
-        if sum < *threshold {
-            sum = 0.0;
+impl CompositeScorer for SumOfScorers {
+    fn combine(&self, children: &[f32]) -> f32 {
+        let sum = children.iter().sum();
+        if sum < self.threshold {
+            0.0
+        } else {
+            f32::clamp(sum, 0.0, 1.0)
         }
-        scores.get_mut(sos_ent).unwrap().set(sum.clamp(0.0, 1.0));
     }
 }
 
@@ -330,23 +655,14 @@ impl ProductOfScorers {
     }
 }
 
-pub fn product_of_scorers_system(
-    query: Query<(Entity, &ProductOfScorers, &Children)>,
-    mut scores: Query<&mut Score>,
-) {
-    for (this_entity, this, scorers) in query.iter() {
-        let mut product = 1.0;
-
-        for &child in scorers.iter() {
-            product *= scores.get_mut(child).unwrap().0;
-        }
-
-        if product < this.threshold {
-            product = 0.0;
+impl CompositeScorer for ProductOfScorers {
+    fn combine(&self, children: &[f32]) -> f32 {
+        let product = children.iter().product();
+        if product < self.threshold {
+            0.0
+        } else {
+            f32::clamp(product, 0.0, 1.0)
         }
-
-        let score = product.clamp(0.0, 1.0);
-        scores.get_mut(this_entity).unwrap().set(score);
     }
 }
 
@@ -361,31 +677,23 @@ impl CompensatedProductOfScorers {
     }
 }
 
-pub fn compensated_product_of_scorers_system(
-    query: Query<(Entity, &CompensatedProductOfScorers, &Children)>,
-    mut scores: Query<&mut Score>,
-) {
-    for (this_entity, this, scorers) in query.iter() {
-        let mut product = 1.0;
-
-        for &child in scorers.iter() {
-            product *= scores.get_mut(child).unwrap().0;
-        }
+impl CompositeScorer for CompensatedProductOfScorers {
+    fn combine(&self, children: &[f32]) -> f32 {
+        let mut product = children.iter().product::<f32>();
 
         // See for example
         // http://www.gdcvault.com/play/1021848/Building-a-Better-Centaur-AI
         if product < 1.0 {
-            let mod_factor = 1.0 - 1.0 / (scorers.len() as f32);
+            let mod_factor = 1.0 - 1.0 / (children.len() as f32);
             let makeup = (1.0 - product) * mod_factor;
             product += makeup * product;
         }
 
-        if product < this.threshold {
-            product = 0.0;
+        if product < self.threshold {
+            0.0
+        } else {
+            product.clamp(0.0, 1.0)
         }
-
-        let score = product.clamp(0.0, 1.0);
-        scores.get_mut(this_entity).unwrap().set(score);
     }
 }
 
@@ -421,27 +729,225 @@ impl WinningScorer {
     }
 }
 
-pub fn winning_scorer_system(
-    query: Query<(Entity, &WinningScorer, &Children)>,
-    mut scores: Query<&mut Score>,
-) {
-    for (this_entity, this, children) in query.iter() {
-        let mut children: Vec<Score> = children
-            .iter()
-            .map(|&entity| scores.get(entity).cloned().unwrap())
-            .collect::<_>();
+impl CompositeScorer for WinningScorer {
+    fn combine(&self, children: &[f32]) -> f32 {
+        let winning = children.iter().copied().max_by(f32::total_cmp);
+        winning
+            .filter(|&score| score >= self.threshold)
+            .map_or(0.0, |score| score.clamp(0.0, 1.0))
+    }
+}
+
+/// How a [`SmoothedScorer`] follows its child [`Score`] over time. All times
+/// are in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum Smoothing {
+    /// Exponential moving average: closes half of the gap to the child
+    /// [`Score`] every `half_life` seconds.
+    Ema { half_life: f32 },
+    /// Like [`Smoothing::Ema`], but with separate half-lives for when the
+    /// child [`Score`] is above (`attack`) or below (`release`) the smoothed
+    /// one.
+    AttackRelease { attack: f32, release: f32 },
+    /// Follows the child [`Score`], changing by at most `per_second` every
+    /// second.
+    MaxRate { per_second: f32 },
+    /// Follows the child [`Score`] up immediately, but holds on to a peak for
+    /// `duration` seconds before following it down.
+    Hold { duration: f32 },
+}
+
+/// Composite Scorer that smooths the [`Score`] of a single child Scorer over
+/// time, so that jumpy perception Scores don't make a Thinker flip-flop
+/// between Actions.
+///
+/// Time is measured with [`Time`], the same way as [`Wait`](crate::Wait),
+/// so smoothing behaves the same regardless of frame rate. The first time
+/// it's evaluated, a [`SmoothedScorer`] starts out at its child's [`Score`].
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct EnemyVisible;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Flee;
+/// # fn main() {
+/// ThinkerSpawner::highest(0.5)
+///     .when(SmoothedScorer::attack_release(0.1, 2.0, EnemyVisible), Flee)
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct SmoothedScorer {
+    smoothing: Smoothing,
+    value: Option<f32>,
+    held: f32,
+}
+
+impl SmoothedScorer {
+    pub fn build(smoothing: Smoothing, scorer: impl ScorerSpawn + 'static) -> impl ScorerSpawn {
+        let this = Self {
+            smoothing,
+            value: None,
+            held: 0.0,
+        };
+        ScorerSpawner::new(this, scorer)
+    }
 
-        children.sort_by(|Score(a), Score(b)| f32::total_cmp(a, b));
+    /// See [`Smoothing::Ema`].
+    pub fn ema(half_life: f32, scorer: impl ScorerSpawn + 'static) -> impl ScorerSpawn {
+        Self::build(Smoothing::Ema { half_life }, scorer)
+    }
 
-        let Score(winning_score_or_zero) = children
-            .last()
-            .cloned()
-            .filter(|&Score(s)| s >= this.threshold)
-            .unwrap_or(Score(0.0));
+    /// See [`Smoothing::AttackRelease`].
+    pub fn attack_release(
+        attack: f32,
+        release: f32,
+        scorer: impl ScorerSpawn + 'static,
+    ) -> impl ScorerSpawn {
+        Self::build(Smoothing::AttackRelease { attack, release }, scorer)
+    }
+
+    /// See [`Smoothing::MaxRate`].
+    pub fn max_rate(per_second: f32, scorer: impl ScorerSpawn + 'static) -> impl ScorerSpawn {
+        Self::build(Smoothing::MaxRate { per_second }, scorer)
+    }
+
+    /// See [`Smoothing::Hold`].
+    pub fn hold(duration: f32, scorer: impl ScorerSpawn + 'static) -> impl ScorerSpawn {
+        Self::build(Smoothing::Hold { duration }, scorer)
+    }
+
+    /// Moves the smoothed value `dt` seconds towards `target`.
+    fn advance(&mut self, target: f32, dt: f32) -> f32 {
+        let Some(value) = self.value else {
+            self.value = Some(target);
+            return target;
+        };
+
+        // Fraction of the gap an EMA with the given half-life closes in `dt`.
+        let ema = |half_life: f32| {
+            if half_life > 0.0 {
+                1.0 - 0.5f32.powf(dt / half_life)
+            } else {
+                1.0
+            }
+        };
+
+        let value = match self.smoothing {
+            Smoothing::Ema { half_life } => value + (target - value) * ema(half_life),
+            Smoothing::AttackRelease { attack, release } => {
+                let half_life = if target > value { attack } else { release };
+                value + (target - value) * ema(half_life)
+            }
+            Smoothing::MaxRate { per_second } => {
+                let step = per_second.abs() * dt;
+                value + (target - value).clamp(-step, step)
+            }
+            Smoothing::Hold { duration } => {
+                if target >= value {
+                    self.held = 0.0;
+                    target
+                } else {
+                    self.held += dt;
+                    if self.held >= duration {
+                        self.held = 0.0;
+                        target
+                    } else {
+                        value
+                    }
+                }
+            }
+        };
+
+        self.value = Some(value);
+        value
+    }
+}
+
+pub(crate) fn combine_smoothed(this: &mut EntityMut, children: &[f32], dt: f32) -> f32 {
+    let Some(&target) = children.first() else {
+        return 0.0;
+    };
+    this.get_mut::<SmoothedScorer>()
+        .map_or(0.0, |mut this| this.advance(target, dt).clamp(0.0, 1.0))
+}
+
+/// Composite Scorer that latches on once the [`Score`] of a single child
+/// Scorer rises to `on` or above, and only lets go once it falls below `off`.
+/// Scores `1.0` while latched, and `0.0` otherwise, or the other way around
+/// when built with [`LatchScorer::inverted`].
+///
+/// Unlike the single `threshold` of [`AllOrNothing`] and friends, the gap
+/// between `on` and `off` keeps conditions like "hungry" or "in danger" from
+/// flickering when the child [`Score`] hovers around a threshold.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct Hunger;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Eat;
+/// # fn main() {
+/// ThinkerSpawner::highest(0.5)
+///     .when(LatchScorer::build(0.8, 0.2, Hunger), Eat)
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct LatchScorer {
+    on: f32,
+    off: f32,
+    inverted: bool,
+    latched: bool,
+}
+
+impl LatchScorer {
+    pub fn build(on: f32, off: f32, scorer: impl ScorerSpawn + 'static) -> impl ScorerSpawn {
+        ScorerSpawner::new(Self::new(on, off, false), scorer)
+    }
+
+    /// Like [`LatchScorer::build`], but Scores `0.0` while latched, and `1.0`
+    /// otherwise.
+    pub fn inverted(on: f32, off: f32, scorer: impl ScorerSpawn + 'static) -> impl ScorerSpawn {
+        ScorerSpawner::new(Self::new(on, off, true), scorer)
+    }
+
+    fn new(on: f32, off: f32, inverted: bool) -> Self {
+        Self {
+            on,
+            off: off.min(on),
+            inverted,
+            latched: false,
+        }
+    }
+
+    /// Whether the child [`Score`] has latched this Scorer on.
+    pub fn is_latched(&self) -> bool {
+        self.latched
+    }
+}
+
+pub(crate) fn combine_latch(this: &mut EntityMut, children: &[f32], _: f32) -> f32 {
+    let Some(mut this) = this.get_mut::<LatchScorer>() else {
+        return 0.0;
+    };
+    let child = children.first().copied().unwrap_or(0.0);
+    if this.latched && child < this.off {
+        this.latched = false;
+    } else if !this.latched && child >= this.on {
+        this.latched = true;
+    }
 
-        scores
-            .get_mut(this_entity)
-            .unwrap()
-            .set(winning_score_or_zero.clamp(0.0, 1.0));
+    if this.latched != this.inverted {
+        1.0
+    } else {
+        0.0
     }
 }
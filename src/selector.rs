@@ -0,0 +1,254 @@
+//! Selectors, which pick one of their Actions based on Scores, just like a
+//! Thinker does, but from within a Composite Action.
+
+use crate::{
+    action::{
+        Action, ActionCommands, ActionOutcome, ActionProgress, ActionSpawn, ActionState,
+        ActionStateQuery, CancelReason,
+    },
+    pickers::{Choice, ChoiceBuilder, FirstToScore, Highest, Picker},
+    scorer::{Score, ScorerSpawn},
+    thinker::Actor,
+};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    system::{Commands, Query},
+    world::Ref,
+};
+use bevy_hierarchy::AddChild;
+use bevy_log as log;
+use std::sync::Arc;
+
+/// [`ActionSpawn`] for the [`Selector`] component.
+pub struct SelectorSpawner {
+    picker: Arc<dyn Picker>,
+    choices: Vec<ChoiceBuilder>,
+    reevaluate: bool,
+}
+
+impl SelectorSpawner {
+    /// Define an [`ScorerSpawn`] and [`ActionSpawn`] pair.
+    pub fn when(
+        mut self,
+        when: impl ScorerSpawn + 'static,
+        then: impl ActionSpawn + 'static,
+    ) -> Self {
+        self.choices.push(ChoiceBuilder {
+            when: Arc::new(when),
+            then: Arc::new(then),
+        });
+        self
+    }
+
+    /// Keep evaluating the Scorers while an Action runs, and switch over to
+    /// another Action (cancelling the running one with
+    /// [`CancelReason::Preempted`]) as soon as the picker prefers it.
+    pub fn reevaluate(mut self) -> Self {
+        self.reevaluate = true;
+        self
+    }
+}
+
+impl ActionSpawn for SelectorSpawner {
+    fn spawn(&self, mut cmd: ActionCommands) -> Action {
+        let selector = cmd.spawn(());
+
+        let choices = self
+            .choices
+            .iter()
+            .map(|ChoiceBuilder { when, then }| Choice {
+                scorer: cmd.push_scorer(selector, when.as_ref()),
+                action: then.clone(),
+            })
+            .collect();
+
+        cmd.insert(
+            selector,
+            Selector {
+                picker: self.picker.clone(),
+                choices,
+                reevaluate: self.reevaluate,
+                current: None,
+                switching: false,
+            },
+        );
+
+        selector
+    }
+}
+
+/// Composite Action that picks one of its Actions based on the Scores of their
+/// Scorers, just like a [`Thinker`](crate::thinker::Thinker) picks between its
+/// choices. This is useful for small utility decisions in the middle of a
+/// [`Sequence`](crate::sequence::Sequence), without a whole separate Thinker.
+///
+/// By default, the Scorers are evaluated once, when the [`Selector`] starts,
+/// and it then sticks with the Action it picked. With
+/// [`SelectorSpawner::reevaluate`], they're evaluated every frame instead, and
+/// the [`Selector`] switches Actions whenever the pick changes.
+///
+/// The [`Selector`] ends with the same result (and [`ActionOutcome`]) as the
+/// Action it picked, and in [`ActionState::Failure`] if none of the choices
+/// scored high enough to be picked.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct Hungry;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct NearFridge;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct NearStove;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct GoToKitchen;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct EatLeftovers;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Cook;
+/// # fn main() {
+/// ThinkerSpawner::highest(0.0)
+///     .when(
+///         Hungry,
+///         Sequence::step((
+///             GoToKitchen,
+///             Selector::highest(0.1)
+///                 .when(NearFridge, EatLeftovers)
+///                 .when(NearStove, Cook),
+///         )),
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct Selector {
+    picker: Arc<dyn Picker>,
+    choices: Vec<Choice>,
+    reevaluate: bool,
+    current: Option<(usize, Action)>,
+    switching: bool,
+}
+
+impl Selector {
+    /// Make a new [`SelectorSpawner`] with given picker.
+    pub fn build(picker: impl Picker + 'static) -> SelectorSpawner {
+        SelectorSpawner {
+            picker: Arc::new(picker),
+            choices: Vec::new(),
+            reevaluate: false,
+        }
+    }
+
+    /// Make a new [`SelectorSpawner`] with [`Highest`] picker.
+    pub fn highest(threshold: f32) -> SelectorSpawner {
+        Self::build(Highest { threshold })
+    }
+
+    /// Make a new [`SelectorSpawner`] with [`FirstToScore`] picker.
+    pub fn first_to_score(threshold: f32) -> SelectorSpawner {
+        Self::build(FirstToScore { threshold })
+    }
+
+    /// Returns the index of the [`SelectorSpawner::when`] choice that is
+    /// currently running, if any.
+    pub fn current(&self) -> Option<usize> {
+        self.current.map(|(index, _)| index)
+    }
+}
+
+/// System that takes care of executing any existing [`Selector`] Actions.
+pub fn selector_system(
+    mut cmd: Commands,
+    mut query: Query<(Entity, &mut Selector, &Actor)>,
+    mut states: Query<ActionStateQuery>,
+    mut outcomes: Query<&mut ActionOutcome>,
+    mut progress: Query<&mut ActionProgress>,
+    scores: Query<&Score>,
+    changes: Query<Ref<Score>>,
+) {
+    for (entity, mut selector, &actor) in query.iter_mut() {
+        let Some((winner, action)) = selector.current else {
+            let Ok(mut this_state) = states.get_mut(entity) else {
+                continue;
+            };
+            match this_state.clone() {
+                ActionState::Executing => (),
+                ActionState::Cancelled => {
+                    this_state.failure();
+                    continue;
+                }
+                ActionState::Success | ActionState::Failure => continue,
+            }
+
+            // Scores start out at 0.0, so wait until all Scorers had a chance
+            // to run before picking.
+            let evaluated = selector.choices.iter().all(|choice| {
+                changes
+                    .get(choice.scorer.0)
+                    .is_ok_and(|score| !score.is_added())
+            });
+            if !evaluated {
+                continue;
+            }
+
+            let Some(index) = selector.picker.pick(&selector.choices, &scores) else {
+                log::debug!("{:?} has nothing to pick", entity);
+                this_state.failure();
+                continue;
+            };
+
+            log::debug!("{:?} picked choice {}", entity, index);
+            let action = selector.choices[index]
+                .action
+                .spawn(ActionCommands::new(&mut cmd, actor));
+            cmd.queue(AddChild {
+                parent: entity,
+                child: action.entity(),
+            });
+            selector.current = Some((index, action));
+            selector.switching = false;
+            continue;
+        };
+
+        let entities = [entity, action.entity()];
+        let Ok([mut this_state, mut child]) = states.get_many_mut(entities) else {
+            continue;
+        };
+
+        let [mut this_progress, child_progress] = progress.many_mut(entities);
+        *this_progress = *child_progress;
+
+        match (this_state.clone(), child.clone()) {
+            (ActionState::Executing, ActionState::Executing) => {
+                if !selector.reevaluate {
+                    continue;
+                }
+                let next = selector.picker.pick(&selector.choices, &scores);
+                if next != Some(winner) {
+                    log::debug!("{:?} switches from choice {}", entity, winner);
+                    selector.switching = true;
+                    child.cancel(Some(CancelReason::Preempted));
+                }
+            }
+            (ActionState::Cancelled, ActionState::Executing) => {
+                child.cancel(this_state.cancel_reason())
+            }
+            (ActionState::Executing | ActionState::Cancelled, ActionState::Cancelled) => (),
+            (ActionState::Executing, _) if selector.switching => {
+                cmd.queue(action.despawn_recursive());
+                selector.current = None;
+            }
+            (ActionState::Executing | ActionState::Cancelled, result) => {
+                log::trace!("{:?} choice {} ended with {:?}", entity, winner, result);
+                let [mut this_outcome, child_outcome] = outcomes.many_mut(entities);
+                *this_outcome = child_outcome.clone();
+                *this_state = result;
+            }
+            (ActionState::Success | ActionState::Failure, _) => (),
+        }
+    }
+}
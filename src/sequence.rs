@@ -1,18 +1,26 @@
 use crate::{
-    action::{Action, ActionCommands, ActionSpawn, ActionState, ActionsList},
+    action::{
+        Action, ActionCommands, ActionOutcome, ActionProgress, ActionSpawn, ActionState,
+        ActionStateQuery, ActionStateQueryItem, ActionsList, CancelReason,
+    },
     thinker::Actor,
 };
 use bevy_ecs::{
     component::Component,
-    entity::Entity,
+    entity::{Entities, Entity},
     query::Without,
-    system::{Commands, Query},
+    system::{Commands, Local, Query},
     world::Mut,
 };
 use bevy_hierarchy::{AddChild, Children};
 use bevy_log as log;
 use bevy_reflect::Reflect;
-use std::sync::Arc;
+use bevy_utils::HashMap;
+use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 /// Configures what mode the [`Sequence`] action will run in.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Reflect)]
@@ -24,30 +32,216 @@ pub enum SequenceMode {
     /// Composite Action that executes a series of steps in sequential order, as
     /// long as each step results in a `Success`ful [`ActionState`].
     Step,
+    /// Reaches success once at least `successes` of the concurrent actions
+    /// reach [`ActionState::Success`], and fails once more than `failures` of
+    /// them reach [`ActionState::Failure`] (or once `successes` can no longer
+    /// be reached).
+    Quorum { successes: usize, failures: usize },
+}
+
+/// How many times a [`SequenceMode::Step`] sequence runs through its steps.
+#[derive(Copy, Clone, Debug)]
+enum Repeat {
+    Once,
+    Times(usize),
+    Forever,
+}
+
+impl Repeat {
+    fn has_next_pass(self, pass: usize) -> bool {
+        match self {
+            Self::Once => false,
+            Self::Times(times) => pass + 1 < times,
+            Self::Forever => true,
+        }
+    }
+}
+
+/// Configures how a [`SequenceMode::Step`] sequence deals with steps that
+/// end in [`ActionState::Failure`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Reflect, Default)]
+pub enum StepFailurePolicy {
+    /// Stop at the first failed step and fail the whole sequence.
+    #[default]
+    Abort,
+    /// Skip failed steps and keep going. The sequence always ends in
+    /// [`ActionState::Success`].
+    Skip,
+    /// Skip failed steps and keep going. The sequence ends in
+    /// [`ActionState::Failure`] if any of the steps failed.
+    SkipThenFail,
+    /// Skip failed steps and keep going. The sequence ends in
+    /// [`ActionState::Success`] if at least this many steps succeeded.
+    AtLeast(usize),
+}
+
+impl StepFailurePolicy {
+    fn succeeds(self, summary: StepSummary) -> bool {
+        match self {
+            Self::Abort | Self::SkipThenFail => summary.failed == 0,
+            Self::Skip => true,
+            Self::AtLeast(count) => summary.succeeded >= count,
+        }
+    }
+}
+
+/// How many steps of a [`SequenceMode::Step`] sequence have finished so far.
+/// See [`Sequence::summary`].
+///
+/// Sequences with a [`StepFailurePolicy`] other than
+/// [`StepFailurePolicy::Abort`] end with their final `StepSummary` as their
+/// [`ActionOutcome`], which is how callers can read it from
+/// [`ActionFinished::outcome`](crate::thinker::ActionFinished::outcome).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Reflect, Default)]
+pub struct StepSummary {
+    /// Number of steps that ended in [`ActionState::Success`].
+    pub succeeded: usize,
+    /// Number of steps that ended in [`ActionState::Failure`].
+    pub failed: usize,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Shuffle {
+    Entropy,
+    Seed(u64),
+}
+
+/// Where an interrupted [`SequenceMode::Step`] sequence left off.
+struct Checkpoint {
+    active_step: usize,
+    order: Vec<usize>,
+    pass: usize,
+    summary: StepSummary,
+    rng: Option<SmallRng>,
 }
 
+/// Checkpoints of interrupted resumable sequences, keyed by actor. Shared
+/// between a [`SequenceSpawner`] and the [`Sequence`]s it spawns.
+type Checkpoints = Arc<Mutex<HashMap<Entity, Checkpoint>>>;
+
 /// [`ActionSpawn`] for the [`Sequence`] component.
 pub struct SequenceSpawner {
     mode: SequenceMode,
     actions: Vec<Arc<dyn ActionSpawn>>,
+    repeat: Repeat,
+    shuffle: Option<Shuffle>,
+    on_failure: StepFailurePolicy,
+    checkpoints: Option<Checkpoints>,
+}
+
+impl SequenceSpawner {
+    fn new(mode: SequenceMode, actions: Vec<Arc<dyn ActionSpawn>>) -> Self {
+        Self {
+            mode,
+            actions,
+            repeat: Repeat::Once,
+            shuffle: None,
+            on_failure: StepFailurePolicy::Abort,
+            checkpoints: None,
+        }
+    }
+
+    /// Configure what happens when a step fails. Defaults to
+    /// [`StepFailurePolicy::Abort`]. Only affects [`SequenceMode::Step`].
+    pub fn on_step_failure(mut self, policy: StepFailurePolicy) -> Self {
+        self.on_failure = policy;
+        self
+    }
+
+    /// Run through the steps `times` times in total. Only affects
+    /// [`SequenceMode::Step`].
+    ///
+    /// Panics if `times` is `0`.
+    pub fn repeat(mut self, times: usize) -> Self {
+        assert!(times > 0, "a sequence has to run at least once");
+        self.repeat = Repeat::Times(times);
+        self
+    }
+
+    /// Run through the steps over and over again, until a step fails or the
+    /// sequence gets cancelled. Only affects [`SequenceMode::Step`].
+    pub fn forever(mut self) -> Self {
+        self.repeat = Repeat::Forever;
+        self
+    }
+
+    /// Run the steps in a random order, reshuffled on every pass. Only
+    /// affects [`SequenceMode::Step`].
+    pub fn shuffle(mut self) -> Self {
+        self.shuffle = Some(Shuffle::Entropy);
+        self
+    }
+
+    /// Same as [`SequenceSpawner::shuffle`], but the order is driven by an RNG
+    /// seeded with `seed`, so every spawned [`Sequence`] runs its steps in the
+    /// same order.
+    pub fn shuffle_with_seed(mut self, seed: u64) -> Self {
+        self.shuffle = Some(Shuffle::Seed(seed));
+        self
+    }
+
+    /// Remember where the sequence was when it got cancelled, and pick up
+    /// from the interrupted step (instead of starting over) the next time it
+    /// gets spawned for the same actor. Progress is kept separately for every
+    /// actor and every choice this spawner is used in. Only affects
+    /// [`SequenceMode::Step`].
+    pub fn resumable(mut self) -> Self {
+        self.checkpoints = Some(Checkpoints::default());
+        self
+    }
+
+    /// Fail once more than `failures` of the actions fail, even if the
+    /// required number of successes could still be reached. Only affects
+    /// [`SequenceMode::Quorum`].
+    pub fn max_failures(mut self, failures: usize) -> Self {
+        if let SequenceMode::Quorum { successes, .. } = self.mode {
+            self.mode = SequenceMode::Quorum {
+                successes,
+                failures,
+            };
+        }
+        self
+    }
 }
 
 impl ActionSpawn for SequenceSpawner {
     fn spawn(&self, mut cmd: ActionCommands) -> Action {
-        let action = cmd.spawn(Sequence {
+        let mut sequence = Sequence {
             mode: self.mode,
             active_step: 0,
             steps: self.actions.clone(),
-        });
+            order: (0..self.actions.len()).collect(),
+            repeat: self.repeat,
+            pass: 0,
+            on_failure: self.on_failure,
+            summary: StepSummary::default(),
+            rng: self.shuffle.map(|shuffle| match shuffle {
+                Shuffle::Entropy => SmallRng::from_entropy(),
+                Shuffle::Seed(seed) => SmallRng::seed_from_u64(seed),
+            }),
+            checkpoints: self.checkpoints.clone(),
+        };
+
+        let first_step = match self.mode {
+            SequenceMode::Join | SequenceMode::Race | SequenceMode::Quorum { .. } => None,
+            SequenceMode::Step => {
+                if !sequence.resume(cmd.actor()) {
+                    sequence.reshuffle();
+                }
+                sequence.active().cloned()
+            }
+        };
+
+        let action = cmd.spawn(sequence);
 
         match self.mode {
-            SequenceMode::Join | SequenceMode::Race => {
+            SequenceMode::Join | SequenceMode::Race | SequenceMode::Quorum { .. } => {
                 for child in &self.actions {
                     cmd.push_child(action, child.as_ref());
                 }
             }
             SequenceMode::Step => {
-                if let Some(child) = self.actions.first() {
+                if let Some(child) = first_step {
                     cmd.push_child(action, child.as_ref());
                 }
             }
@@ -63,6 +257,33 @@ impl ActionSpawn for SequenceSpawner {
 /// * [`SequenceMode::Join`] succeeds when **all** of the actions
 ///   succeed.
 /// * [`SequenceMode::Race`] succeeds when **any** of the actions succeed.
+/// * [`SequenceMode::Quorum`] succeeds when **at least N** of the actions
+///   succeed.
+///
+/// Once the outcome is decided, any actions that are still executing get
+/// cancelled. The [`Sequence`] adopts the [`ActionOutcome`] of the action that
+/// decided its result. [`SequenceMode::Step`] sequences adopt the
+/// [`ActionOutcome`] of every step as it finishes, so later steps can read what
+/// earlier steps produced from their parent, and end with their
+/// [`StepSummary`] when failed steps were skipped.
+///
+/// The [`ActionProgress`] of a [`Sequence`] combines the progress of its
+/// actions. [`SequenceMode::Step`] sequences report the number of finished
+/// steps plus the progress of the current step (per pass, for
+/// [`SequenceSpawner::forever`]), [`SequenceMode::Join`] sequences report the
+/// mean progress of their actions, [`SequenceMode::Race`] sequences the
+/// progress of the action that is furthest along, and
+/// [`SequenceMode::Quorum`] sequences the mean progress of the N actions that
+/// are furthest along.
+///
+/// [`SequenceMode::Step`] sequences can also be repeated a number of times
+/// ([`SequenceSpawner::repeat`]), looped until they fail or get cancelled
+/// ([`SequenceSpawner::forever`]), and have their steps run in a random order
+/// ([`SequenceSpawner::shuffle`]). By default, they fail as soon as one of
+/// their steps fails, but they can be configured to skip failed steps instead
+/// ([`SequenceSpawner::on_step_failure`]). Sequences that get cancelled start
+/// over from the first step by default, unless they are
+/// [`SequenceSpawner::resumable`].
 ///
 /// ### Example
 ///
@@ -82,69 +303,271 @@ impl ActionSpawn for SequenceSpawner {
 /// # }
 /// ```
 ///
+/// Patrolling a set of waypoints in random order, forever:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct GoTo(Vec2);
+/// # fn main() {
+/// ThinkerSpawner::highest(0.0)
+///     .when(
+///         MyScorer,
+///         Sequence::step((GoTo(Vec2::X), GoTo(Vec2::Y), GoTo(Vec2::ZERO)))
+///             .shuffle()
+///             .forever(),
+///     )
+/// # ;
+/// # }
+/// ```
 #[derive(Component)]
 pub struct Sequence {
     mode: SequenceMode,
     active_step: usize,
     steps: Vec<Arc<dyn ActionSpawn>>,
+    order: Vec<usize>,
+    repeat: Repeat,
+    pass: usize,
+    on_failure: StepFailurePolicy,
+    summary: StepSummary,
+    rng: Option<SmallRng>,
+    checkpoints: Option<Checkpoints>,
 }
 
 impl Sequence {
     /// Construct a new [`SequenceSpawner`] to define the actions to take.
     pub fn join<B: ActionsList>(actions: B) -> SequenceSpawner {
-        SequenceSpawner {
-            mode: SequenceMode::Join,
-            actions: ActionsList::build(actions),
-        }
+        SequenceSpawner::new(SequenceMode::Join, ActionsList::build(actions))
     }
 
     /// Construct a new [`SequenceSpawner`] to define the actions to take.
     pub fn race<B: ActionsList>(actions: B) -> SequenceSpawner {
-        SequenceSpawner {
-            mode: SequenceMode::Race,
-            actions: ActionsList::build(actions),
-        }
+        SequenceSpawner::new(SequenceMode::Race, ActionsList::build(actions))
     }
 
     /// Construct a new [`SequenceSpawner`] to define the actions to take.
     pub fn step<B: ActionsList>(actions: B) -> SequenceSpawner {
-        SequenceSpawner {
-            mode: SequenceMode::Step,
-            actions: ActionsList::build(actions),
+        SequenceSpawner::new(SequenceMode::Step, ActionsList::build(actions))
+    }
+
+    /// Construct a new [`SequenceSpawner`] that runs the actions concurrently
+    /// and succeeds once at least `successes` of them succeed. Use
+    /// [`SequenceSpawner::max_failures`] to fail early.
+    pub fn quorum<B: ActionsList>(successes: usize, actions: B) -> SequenceSpawner {
+        let actions = ActionsList::build(actions);
+        let failures = actions.len().saturating_sub(successes);
+        let mode = SequenceMode::Quorum {
+            successes,
+            failures,
+        };
+        SequenceSpawner::new(mode, actions)
+    }
+
+    /// Returns how many steps have succeeded and failed so far. Only tracked
+    /// for [`SequenceMode::Step`].
+    pub fn summary(&self) -> StepSummary {
+        self.summary
+    }
+
+    /// Returns the step that is currently running, if any.
+    fn active(&self) -> Option<&Arc<dyn ActionSpawn>> {
+        let &index = self.order.get(self.active_step)?;
+        self.steps.get(index)
+    }
+
+    /// Combines the progress of the actions into the progress of the whole
+    /// sequence.
+    fn combine_progress(&self, children: &mut [(f32, Option<Duration>)]) -> ActionProgress {
+        let mut progress = ActionProgress::default();
+        let count = children.len().max(1);
+        let sum =
+            |children: &[(f32, Option<Duration>)]| children.iter().map(|&(p, _)| p).sum::<f32>();
+
+        match self.mode {
+            SequenceMode::Join => {
+                progress.set(sum(children) / count as f32);
+                let etas = children.iter().map(|&(_, eta)| eta);
+                progress.set_eta(
+                    etas.collect::<Option<Vec<_>>>()
+                        .and_then(|etas| etas.into_iter().max()),
+                );
+            }
+            SequenceMode::Race => {
+                progress.set(children.iter().map(|&(p, _)| p).fold(0.0, f32::max));
+                progress.set_eta(children.iter().filter_map(|&(_, eta)| eta).min());
+            }
+            SequenceMode::Quorum { successes, .. } => {
+                let successes = successes.clamp(1, count);
+                children.sort_by(|(a, _), (b, _)| f32::total_cmp(b, a));
+                progress.set(sum(&children[..successes.min(children.len())]) / successes as f32);
+
+                let mut etas = children
+                    .iter()
+                    .filter_map(|&(_, eta)| eta)
+                    .collect::<Vec<_>>();
+                etas.sort();
+                progress.set_eta(etas.get(successes - 1).copied());
+            }
+            SequenceMode::Step => {
+                let (child, eta) = children.first().copied().unwrap_or_default();
+                let (pass, passes) = match self.repeat {
+                    Repeat::Once | Repeat::Forever => (0, 1),
+                    Repeat::Times(times) => (self.pass, times.max(1)),
+                };
+                let steps = self.steps.len().max(1);
+                let done = (pass * steps + self.active_step) as f32 + child;
+                progress.set(done / (passes * steps) as f32);
+
+                let last_pass = !matches!(self.repeat, Repeat::Forever) && pass + 1 >= passes;
+                if last_pass && self.active_step + 1 >= steps {
+                    progress.set_eta(eta);
+                }
+            }
         }
+
+        progress
+    }
+
+    fn reshuffle(&mut self) {
+        if let Some(rng) = &mut self.rng {
+            self.order.shuffle(rng);
+        }
+    }
+
+    /// Picks up where an interrupted sequence for `actor` left off, if there
+    /// is one. Returns whether there was anything to resume.
+    fn resume(&mut self, actor: Entity) -> bool {
+        let Some(checkpoints) = &self.checkpoints else {
+            return false;
+        };
+        let Some(checkpoint) = checkpoints.lock().unwrap().remove(&actor) else {
+            return false;
+        };
+
+        self.active_step = checkpoint.active_step;
+        self.order = checkpoint.order;
+        self.pass = checkpoint.pass;
+        self.summary = checkpoint.summary;
+        if checkpoint.rng.is_some() {
+            self.rng = checkpoint.rng;
+        }
+        true
+    }
+
+    /// Moves on to the next step, starting the next pass after the last
+    /// step. Returns `false` once there are no steps left to run.
+    fn advance(&mut self) -> bool {
+        if self.active_step < self.steps.len() - 1 {
+            self.active_step += 1;
+        } else if self.repeat.has_next_pass(self.pass) {
+            self.pass += 1;
+            self.active_step = 0;
+            self.reshuffle();
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Remembers where this sequence got interrupted, so the next one spawned
+    /// for `actor` can resume from the active step. Also forgets about
+    /// actors that have been despawned since they got interrupted.
+    fn checkpoint(&mut self, actor: Entity, entities: &Entities) {
+        let Some(checkpoints) = &self.checkpoints else {
+            return;
+        };
+
+        let checkpoint = Checkpoint {
+            active_step: self.active_step,
+            order: self.order.clone(),
+            pass: self.pass,
+            summary: self.summary,
+            rng: self.rng.take(),
+        };
+        let mut checkpoints = checkpoints.lock().unwrap();
+        checkpoints.retain(|&actor, _| entities.contains(actor));
+        checkpoints.insert(actor, checkpoint);
     }
 }
 
 /// System that takes care of executing any existing [`Concurrently`] Actions.
 pub fn sequence_system(
     mut cmd: Commands,
-    mut query: Query<(Entity, &mut ActionState, &mut Sequence, &Children, &Actor)>,
-    mut states: Query<&mut ActionState, Without<Sequence>>,
+    mut query: Query<(Entity, ActionStateQuery, &mut Sequence, &Children, &Actor)>,
+    mut states: Query<ActionStateQuery, Without<Sequence>>,
+    mut outcomes: Query<&mut ActionOutcome>,
+    mut progress: Query<&mut ActionProgress>,
+    mut cache: Local<Vec<(f32, Option<Duration>)>>,
+    entities: &Entities,
 ) {
     for (parent, this_state, sequence, actions, &actor) in query.iter_mut() {
         let mode = sequence.mode;
         log::trace!("start {:?} {:?}", mode, parent);
+
+        if !this_state.is_done() {
+            // Finished actions count as done, whatever they reported.
+            cache.extend(actions.iter().map(|&child| {
+                let done = states.get(child).is_ok_and(|state| state.is_done());
+                match progress.get(child) {
+                    Ok(_) if done => (1.0, Some(Duration::ZERO)),
+                    Ok(child) => (child.get(), child.eta()),
+                    Err(_) => (0.0, None),
+                }
+            }));
+            *progress.get_mut(parent).unwrap() = sequence.combine_progress(&mut cache);
+            cache.clear();
+        }
+
         match mode {
-            SequenceMode::Join => exec_join(this_state, actions, &mut states),
-            SequenceMode::Race => exec_race(this_state, actions, &mut states),
+            SequenceMode::Join => {
+                exec_join(this_state, actions, &mut states, &mut outcomes, parent)
+            }
+            SequenceMode::Race => {
+                exec_race(this_state, actions, &mut states, &mut outcomes, parent)
+            }
+            SequenceMode::Quorum {
+                successes,
+                failures,
+            } => exec_quorum(
+                this_state,
+                actions,
+                &mut states,
+                &mut outcomes,
+                parent,
+                successes,
+                failures,
+            ),
             SequenceMode::Step => exec_step(
                 this_state,
                 actions,
                 &mut states,
-                &mut cmd,
+                &mut outcomes,
                 parent,
+                &mut cmd,
                 sequence,
                 actor,
+                entities,
             ),
         }
         log::trace!("end {:?} {:?}", mode, parent);
     }
 }
 
+/// Copies the [`ActionOutcome`] of `child` over to `parent`.
+fn adopt_outcome(outcomes: &mut Query<&mut ActionOutcome>, parent: Entity, child: Entity) {
+    let outcome = outcomes.get(child).unwrap().clone();
+    *outcomes.get_mut(parent).unwrap() = outcome;
+}
+
 fn exec_join(
-    mut this_state: Mut<ActionState>,
+    mut this_state: ActionStateQueryItem,
     actions: &Children,
-    states: &mut Query<&mut ActionState, Without<Sequence>>,
+    states: &mut Query<ActionStateQuery, Without<Sequence>>,
+    outcomes: &mut Query<&mut ActionOutcome>,
+    parent: Entity,
 ) {
     match this_state.clone() {
         ActionState::Executing => {
@@ -156,7 +579,9 @@ fn exec_join(
                 all_success &= child.is_success();
                 match *child {
                     ActionState::Failure => failed_index = Some(index),
-                    ActionState::Executing if failed_index.is_some() => child.cancel(),
+                    ActionState::Executing if failed_index.is_some() => {
+                        child.cancel(Some(CancelReason::ParentFinished))
+                    }
                     ActionState::Executing | ActionState::Cancelled | ActionState::Success => (),
                 }
             }
@@ -165,8 +590,12 @@ fn exec_join(
                 this_state.success();
             } else if let Some(index) = failed_index {
                 for &child in actions.iter().take(index) {
-                    states.get_mut(child).unwrap().cancel_if_executing();
+                    states
+                        .get_mut(child)
+                        .unwrap()
+                        .cancel_if_executing(Some(CancelReason::ParentFinished));
                 }
+                adopt_outcome(outcomes, parent, actions[index]);
                 this_state.failure();
             }
         }
@@ -179,7 +608,7 @@ fn exec_join(
                 all_done &= child.is_done();
                 match *child {
                     ActionState::Failure => any_err = true,
-                    ActionState::Executing => child.cancel(),
+                    ActionState::Executing => child.cancel(this_state.cancel_reason()),
                     ActionState::Success | ActionState::Cancelled => (),
                 }
             }
@@ -196,9 +625,11 @@ fn exec_join(
 }
 
 fn exec_race(
-    mut this_state: Mut<ActionState>,
+    mut this_state: ActionStateQueryItem,
     actions: &Children,
-    states: &mut Query<&mut ActionState, Without<Sequence>>,
+    states: &mut Query<ActionStateQuery, Without<Sequence>>,
+    outcomes: &mut Query<&mut ActionOutcome>,
+    parent: Entity,
 ) {
     match this_state.clone() {
         ActionState::Executing => {
@@ -210,7 +641,9 @@ fn exec_race(
                 all_failure &= child.is_failure();
                 match *child {
                     ActionState::Success => succeed_index = Some(index),
-                    ActionState::Executing if succeed_index.is_some() => child.cancel(),
+                    ActionState::Executing if succeed_index.is_some() => {
+                        child.cancel(Some(CancelReason::ParentFinished))
+                    }
                     ActionState::Executing | ActionState::Cancelled | ActionState::Failure => (),
                 }
             }
@@ -219,8 +652,12 @@ fn exec_race(
                 this_state.failure();
             } else if let Some(index) = succeed_index {
                 for &child in actions.iter().take(index) {
-                    states.get_mut(child).unwrap().cancel_if_executing();
+                    states
+                        .get_mut(child)
+                        .unwrap()
+                        .cancel_if_executing(Some(CancelReason::ParentFinished));
                 }
+                adopt_outcome(outcomes, parent, actions[index]);
                 this_state.success();
             }
         }
@@ -233,7 +670,7 @@ fn exec_race(
                 all_done &= child.is_done();
                 match *child {
                     ActionState::Success => any_ok = true,
-                    ActionState::Executing => child.cancel(),
+                    ActionState::Executing => child.cancel(this_state.cancel_reason()),
                     ActionState::Failure | ActionState::Cancelled => (),
                 }
             }
@@ -249,15 +686,95 @@ fn exec_race(
     }
 }
 
+fn exec_quorum(
+    mut this_state: ActionStateQueryItem,
+    actions: &Children,
+    states: &mut Query<ActionStateQuery, Without<Sequence>>,
+    outcomes: &mut Query<&mut ActionOutcome>,
+    parent: Entity,
+    successes: usize,
+    failures: usize,
+) {
+    match this_state.clone() {
+        ActionState::Executing => {
+            let mut succeeded = 0;
+            let mut failed = 0;
+
+            for &child_entity in actions.iter() {
+                match *states.get(child_entity).unwrap() {
+                    ActionState::Success => succeeded += 1,
+                    ActionState::Failure => failed += 1,
+                    ActionState::Executing | ActionState::Cancelled => (),
+                }
+            }
+
+            let pending = actions.len() - succeeded - failed;
+            let success = if succeeded >= successes {
+                true
+            } else if failed > failures || succeeded + pending < successes {
+                false
+            } else {
+                return;
+            };
+
+            let decided_by = actions.iter().copied().find(|&child| {
+                let child = states.get(child).unwrap();
+                (success && child.is_success()) || (!success && child.is_failure())
+            });
+            if let Some(child) = decided_by {
+                adopt_outcome(outcomes, parent, child);
+            }
+
+            for &child in actions.iter() {
+                states
+                    .get_mut(child)
+                    .unwrap()
+                    .cancel_if_executing(Some(CancelReason::ParentFinished));
+            }
+
+            if success {
+                this_state.success();
+            } else {
+                this_state.failure();
+            }
+        }
+        ActionState::Cancelled => {
+            let mut succeeded = 0;
+            let mut all_done = true;
+
+            for &child_entity in actions.iter() {
+                let mut child = states.get_mut(child_entity).unwrap();
+                all_done &= child.is_done();
+                match *child {
+                    ActionState::Success => succeeded += 1,
+                    ActionState::Executing => child.cancel(this_state.cancel_reason()),
+                    ActionState::Failure | ActionState::Cancelled => (),
+                }
+            }
+
+            if all_done && succeeded >= successes {
+                this_state.success()
+            }
+            if all_done && succeeded < successes {
+                this_state.failure()
+            }
+        }
+        ActionState::Success | ActionState::Failure => {}
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn exec_step(
-    mut this_state: Mut<ActionState>,
+    mut this_state: ActionStateQueryItem,
     actions: &Children,
-    states: &mut Query<&mut ActionState, Without<Sequence>>,
+    states: &mut Query<ActionStateQuery, Without<Sequence>>,
+    outcomes: &mut Query<&mut ActionOutcome>,
+    parent: Entity,
 
     cmd: &mut Commands,
-    parent: Entity,
     mut sequence: Mut<Sequence>,
     actor: Actor,
+    entities: &Entities,
 ) {
     let Some(active) = actions.first().copied().map(Action) else {
         return;
@@ -267,29 +784,202 @@ fn exec_step(
 
     match (this_state.clone(), active_state.clone()) {
         (ActionState::Executing, ActionState::Executing | ActionState::Cancelled) => (),
-        (ActionState::Executing, ActionState::Success) => {
+        (ActionState::Executing, ActionState::Success | ActionState::Failure) => {
             cmd.queue(active.despawn_recursive());
+            adopt_outcome(outcomes, parent, active.entity());
 
-            if sequence.active_step == sequence.steps.len() - 1 {
-                // We're done! Let's just be successful
-                this_state.success();
+            if active_state.is_success() {
+                sequence.summary.succeeded += 1;
             } else {
-                sequence.active_step += 1;
-                let child =
-                    sequence.steps[sequence.active_step].spawn(ActionCommands::new(cmd, actor));
-                let child = child.entity();
-                cmd.queue(AddChild { parent, child });
+                sequence.summary.failed += 1;
+                if sequence.on_failure == StepFailurePolicy::Abort {
+                    this_state.failure();
+                    return;
+                }
+                log::trace!("{:?} skips failed step {:?}", parent, active);
             }
+
+            if sequence.advance() {
+                log::trace!("{:?} moves on to step {}", parent, sequence.active_step);
+            } else {
+                // When failed steps were skipped, the last step's outcome
+                // doesn't say why the sequence ended the way it did.
+                if sequence.on_failure != StepFailurePolicy::Abort {
+                    outcomes.get_mut(parent).unwrap().set(sequence.summary);
+                }
+                if sequence.on_failure.succeeds(sequence.summary) {
+                    // We're done! Let's just be successful
+                    this_state.success();
+                } else {
+                    this_state.failure();
+                }
+                return;
+            }
+
+            let child = sequence
+                .active()
+                .unwrap()
+                .spawn(ActionCommands::new(cmd, actor));
+            let child = child.entity();
+            cmd.queue(AddChild { parent, child });
         }
-        (ActionState::Executing, ActionState::Failure) => {
-            cmd.queue(active.despawn_recursive());
-            this_state.failure();
+
+        (ActionState::Cancelled, ActionState::Executing) => {
+            active_state.cancel(this_state.cancel_reason())
         }
+        (ActionState::Cancelled, ActionState::Success | ActionState::Failure) => {
+            // A step that still managed to succeed doesn't need to run again.
+            let mut remaining = true;
+            if active_state.is_success() {
+                sequence.summary.succeeded += 1;
+                remaining = sequence.advance();
+            }
+
+            // There's nobody left to resume for if the actor is gone.
+            if remaining && this_state.cancel_reason() != Some(CancelReason::ActorGone) {
+                log::trace!("{:?} interrupted at step {}", parent, sequence.active_step);
+                sequence.checkpoint(actor.entity(), entities);
+            }
 
-        (ActionState::Cancelled, ActionState::Executing) => active_state.cancel(),
-        (ActionState::Cancelled, ActionState::Success) => this_state.success(),
-        (ActionState::Cancelled, ActionState::Failure) => this_state.failure(),
+            adopt_outcome(outcomes, parent, active.entity());
+            if active_state.is_success() {
+                this_state.success();
+            } else {
+                this_state.failure();
+            }
+        }
 
         (_, _) => (),
     }
 }
+
+/// [`ActionSpawn`] for the [`Finally`] component.
+pub struct FinallySpawner {
+    action: Arc<dyn ActionSpawn>,
+    cleanup: Arc<dyn ActionSpawn>,
+}
+
+impl ActionSpawn for FinallySpawner {
+    fn spawn(&self, mut cmd: ActionCommands) -> Action {
+        let finally = cmd.spawn(());
+        let action = cmd.push_child(finally, self.action.as_ref());
+
+        cmd.insert(
+            finally,
+            Finally {
+                action,
+                cleanup: self.cleanup.clone(),
+                result: None,
+            },
+        );
+
+        finally
+    }
+}
+
+/// Composite Action that runs an Action, and then a cleanup Action, no matter
+/// how the first one ended: in [`ActionState::Success`],
+/// [`ActionState::Failure`], or because it got cancelled.
+///
+/// The [`Finally`] ends with the same result (and [`ActionOutcome`]) as the
+/// first Action, once the cleanup Action is done. The cleanup Action never
+/// gets cancelled: if the [`Finally`] gets cancelled, it passes the
+/// cancellation on to the first Action, and then lets the cleanup Action run
+/// to completion before finishing. Keep cleanup Actions short, since the
+/// Thinker waits for them before moving on to its next Action.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct Hungry;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct ReserveStove;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Cook;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct ReleaseStove;
+/// # fn main() {
+/// ThinkerSpawner::highest(0.0)
+///     .when(
+///         Hungry,
+///         Finally::build(Sequence::step((ReserveStove, Cook)), ReleaseStove),
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct Finally {
+    action: Action,
+    cleanup: Arc<dyn ActionSpawn>,
+    result: Option<ActionState>,
+}
+
+impl Finally {
+    /// Construct a new [`FinallySpawner`] that runs `action`, followed by
+    /// `cleanup`.
+    pub fn build(
+        action: impl ActionSpawn + 'static,
+        cleanup: impl ActionSpawn + 'static,
+    ) -> FinallySpawner {
+        FinallySpawner {
+            action: Arc::new(action),
+            cleanup: Arc::new(cleanup),
+        }
+    }
+}
+
+/// System that takes care of executing any existing [`Finally`] Actions.
+pub fn finally_system(
+    mut cmd: Commands,
+    mut query: Query<(Entity, &mut Finally, &Actor)>,
+    mut states: Query<ActionStateQuery>,
+    mut outcomes: Query<&mut ActionOutcome>,
+    mut progress: Query<&mut ActionProgress>,
+) {
+    for (parent, mut finally, &actor) in query.iter_mut() {
+        let entities = [parent, finally.action.entity()];
+        let Ok([mut this_state, mut child]) = states.get_many_mut(entities) else {
+            continue;
+        };
+        if this_state.is_done() {
+            continue;
+        }
+
+        let Some(result) = finally.result.clone() else {
+            // Still running the first Action.
+            let [mut this_progress, child_progress] = progress.many_mut(entities);
+            *this_progress = *child_progress;
+
+            match (this_state.clone(), child.clone()) {
+                (ActionState::Cancelled, ActionState::Executing) => {
+                    child.cancel(this_state.cancel_reason())
+                }
+                (_, ActionState::Executing | ActionState::Cancelled) => (),
+                (_, result) => {
+                    log::trace!("{:?} action ended with {:?}, cleaning up", parent, result);
+                    adopt_outcome(&mut outcomes, parent, finally.action.entity());
+                    cmd.queue(finally.action.despawn_recursive());
+
+                    let cleanup = finally.cleanup.spawn(ActionCommands::new(&mut cmd, actor));
+                    cmd.queue(AddChild {
+                        parent,
+                        child: cleanup.entity(),
+                    });
+                    finally.action = cleanup;
+                    finally.result = Some(result);
+                }
+            }
+            continue;
+        };
+
+        // Running the cleanup Action, which only ever gets cancelled if it
+        // was the one to cancel itself.
+        if child.is_done() {
+            log::trace!("{:?} cleanup ended with {:?}", parent, *child);
+            *this_state = result;
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! State machines, for behaviors that are easier to describe as a handful of
+//! states and the transitions between them than as utility choices.
+
+use crate::{
+    action::{
+        Action, ActionCommands, ActionOutcome, ActionProgress, ActionSpawn, ActionState,
+        ActionStateQuery, CancelReason,
+    },
+    scorer::{Score, Scorer, ScorerSpawn},
+    thinker::Actor,
+};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    system::{Commands, Query},
+    world::Ref,
+};
+use bevy_hierarchy::AddChild;
+use bevy_log as log;
+use std::sync::Arc;
+
+struct StateSpawner {
+    name: &'static str,
+    action: Arc<dyn ActionSpawn>,
+}
+
+enum Trigger {
+    Success,
+    Failure,
+    Score(Arc<dyn ScorerSpawn>, f32),
+}
+
+struct TransitionSpawner {
+    from: &'static str,
+    trigger: Trigger,
+    to: &'static str,
+}
+
+/// [`ActionSpawn`] for the [`StateMachine`] component.
+pub struct StateMachineSpawner {
+    initial: &'static str,
+    states: Vec<StateSpawner>,
+    transitions: Vec<TransitionSpawner>,
+}
+
+impl StateMachineSpawner {
+    /// Add a state called `name`, which runs `action` for as long as the
+    /// [`StateMachine`] is in it.
+    pub fn state(mut self, name: &'static str, action: impl ActionSpawn + 'static) -> Self {
+        assert!(
+            self.index(name).is_none(),
+            "state {name:?} is declared more than once"
+        );
+        self.states.push(StateSpawner {
+            name,
+            action: Arc::new(action),
+        });
+        self
+    }
+
+    /// Move from state `from` to state `to` when the Action of `from` ends in
+    /// [`ActionState::Success`].
+    pub fn on_success(self, from: &'static str, to: &'static str) -> Self {
+        self.transition(from, Trigger::Success, to)
+    }
+
+    /// Move from state `from` to state `to` when the Action of `from` ends in
+    /// [`ActionState::Failure`].
+    pub fn on_failure(self, from: &'static str, to: &'static str) -> Self {
+        self.transition(from, Trigger::Failure, to)
+    }
+
+    /// Move from state `from` to state `to` once `scorer` scores at or above
+    /// `threshold`, cancelling the Action of `from` with
+    /// [`CancelReason::Transition`]. The transition happens once that Action
+    /// is done.
+    pub fn when(
+        self,
+        from: &'static str,
+        scorer: impl ScorerSpawn + 'static,
+        threshold: f32,
+        to: &'static str,
+    ) -> Self {
+        self.transition(from, Trigger::Score(Arc::new(scorer), threshold), to)
+    }
+
+    fn transition(mut self, from: &'static str, trigger: Trigger, to: &'static str) -> Self {
+        self.transitions
+            .push(TransitionSpawner { from, trigger, to });
+        self
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|state| state.name == name)
+    }
+
+    /// Returns the first state that's started in, or transitioned from or to,
+    /// but never declared. States can be declared after their transitions,
+    /// so this can only be checked once the machine is spawned.
+    fn undeclared(&self) -> Option<&'static str> {
+        let transitions = self
+            .transitions
+            .iter()
+            .flat_map(|transition| [transition.from, transition.to]);
+        std::iter::once(self.initial)
+            .chain(transitions)
+            .find(|name| self.index(name).is_none())
+    }
+
+    fn resolve(&self, name: &'static str) -> usize {
+        self.index(name)
+            .expect("states are checked before spawning")
+    }
+}
+
+impl ActionSpawn for StateMachineSpawner {
+    fn spawn(&self, mut cmd: ActionCommands) -> Action {
+        let machine = cmd.spawn(());
+        if let Some(name) = self.undeclared() {
+            log::error!(
+                "{:?} can't run, state {:?} isn't declared",
+                machine.entity(),
+                name
+            );
+            cmd.insert(machine, ActionState::Failure);
+            return machine;
+        }
+
+        let mut states = self
+            .states
+            .iter()
+            .map(|state| State {
+                name: state.name,
+                action: state.action.clone(),
+                on_success: None,
+                on_failure: None,
+                when: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        for transition in &self.transitions {
+            let to = self.resolve(transition.to);
+            let from = &mut states[self.resolve(transition.from)];
+            match &transition.trigger {
+                Trigger::Success => from.on_success = Some(to),
+                Trigger::Failure => from.on_failure = Some(to),
+                Trigger::Score(scorer, threshold) => {
+                    let scorer = cmd.push_scorer(machine, scorer.as_ref());
+                    from.when.push((scorer, *threshold, to));
+                }
+            }
+        }
+
+        let current = self.resolve(self.initial);
+        let action = cmd.push_child(machine, states[current].action.as_ref());
+
+        cmd.insert(
+            machine,
+            StateMachine {
+                states,
+                current,
+                action,
+                next: None,
+            },
+        );
+
+        machine
+    }
+}
+
+struct State {
+    name: &'static str,
+    action: Arc<dyn ActionSpawn>,
+    on_success: Option<usize>,
+    on_failure: Option<usize>,
+    when: Vec<(Scorer, f32, usize)>,
+}
+
+/// Composite Action that runs a finite state machine. Every state runs its own
+/// Action, and the machine moves on to another state when that Action ends in
+/// [`ActionState::Success`] or [`ActionState::Failure`]
+/// ([`StateMachineSpawner::on_success`], [`StateMachineSpawner::on_failure`]),
+/// or when a Scorer reaches a threshold ([`StateMachineSpawner::when`]).
+///
+/// The [`StateMachine`] ends with the result of the last Action it ran, as soon
+/// as that Action ends without a transition to take. If the [`StateMachine`]
+/// gets cancelled, it cancels the Action of the current state, and ends with
+/// its result. Like [`SequenceMode::Step`](crate::sequence::SequenceMode::Step)
+/// sequences, it adopts the [`ActionOutcome`] of every Action as it finishes.
+///
+/// Scorers of all transitions are spawned along with the [`StateMachine`], but
+/// only the ones leaving the current state are looked at. The
+/// [`ActionProgress`] of the current state's Action is copied over.
+///
+/// A [`StateMachine`] that starts in, or has transitions from or to, a state
+/// that was never declared logs an error and fails right away.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct OnDuty;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct IntruderNear;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Patrol;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct Challenge;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct RaiseAlarm;
+/// # fn main() {
+/// ThinkerSpawner::highest(0.0)
+///     .when(
+///         OnDuty,
+///         StateMachine::build("patrol")
+///             .state("patrol", Patrol)
+///             .state("challenge", Challenge)
+///             .state("alarm", RaiseAlarm)
+///             .when("patrol", IntruderNear, 0.8, "challenge")
+///             .on_success("challenge", "patrol")
+///             .on_failure("challenge", "alarm"),
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct StateMachine {
+    states: Vec<State>,
+    current: usize,
+    action: Action,
+    next: Option<usize>,
+}
+
+impl StateMachine {
+    /// Construct a new [`StateMachineSpawner`], starting out in the state
+    /// called `initial`.
+    pub fn build(initial: &'static str) -> StateMachineSpawner {
+        StateMachineSpawner {
+            initial,
+            states: Vec::new(),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Returns the name of the current state.
+    pub fn state(&self) -> &'static str {
+        self.states[self.current].name
+    }
+}
+
+/// System that takes care of executing any existing [`StateMachine`] Actions.
+pub fn state_machine_system(
+    mut cmd: Commands,
+    mut query: Query<(Entity, &mut StateMachine, &Actor)>,
+    mut states: Query<ActionStateQuery>,
+    mut outcomes: Query<&mut ActionOutcome>,
+    mut progress: Query<&mut ActionProgress>,
+    scores: Query<Ref<Score>>,
+) {
+    for (entity, mut machine, &actor) in query.iter_mut() {
+        let entities = [entity, machine.action.entity()];
+        let Ok([mut this_state, mut child]) = states.get_many_mut(entities) else {
+            continue;
+        };
+
+        let [mut this_progress, child_progress] = progress.many_mut(entities);
+        *this_progress = *child_progress;
+
+        match (this_state.clone(), child.clone()) {
+            (ActionState::Executing, ActionState::Executing) => {
+                let state = &machine.states[machine.current];
+                // Scores start out at 0.0, so skip the ones whose Scorer
+                // didn't have a chance to run yet.
+                let next = state.when.iter().find_map(|&(scorer, threshold, to)| {
+                    let score = scores.get(scorer.0).ok()?;
+                    (!score.is_added() && score.get() >= threshold).then_some(to)
+                });
+                if let Some(next) = next {
+                    log::debug!("{:?} leaving state {:?}", entity, state.name);
+                    machine.next = Some(next);
+                    child.cancel(Some(CancelReason::Transition));
+                }
+            }
+            (ActionState::Cancelled, ActionState::Executing) => {
+                child.cancel(this_state.cancel_reason())
+            }
+            (ActionState::Executing | ActionState::Cancelled, ActionState::Cancelled) => (),
+            (ActionState::Executing | ActionState::Cancelled, result) => {
+                let [mut this_outcome, child_outcome] = outcomes.many_mut(entities);
+                *this_outcome = child_outcome.clone();
+
+                let state = &machine.states[machine.current];
+                let next = match result {
+                    _ if this_state.is_cancelled() => None,
+                    _ if machine.next.is_some() => machine.next,
+                    ActionState::Success => state.on_success,
+                    _ => state.on_failure,
+                };
+                let Some(next) = next else {
+                    log::trace!("{:?} stopped in state {:?}", entity, state.name);
+                    *this_state = result;
+                    continue;
+                };
+
+                let name = machine.states[next].name;
+                log::debug!("{:?} {:?} -> {:?}", entity, state.name, name);
+                cmd.queue(machine.action.despawn_recursive());
+
+                let action = machine.states[next]
+                    .action
+                    .spawn(ActionCommands::new(&mut cmd, actor));
+                cmd.queue(AddChild {
+                    parent: entity,
+                    child: action.entity(),
+                });
+                machine.action = action;
+                machine.current = next;
+                machine.next = None;
+            }
+            (ActionState::Success | ActionState::Failure, _) => (),
+        }
+    }
+}
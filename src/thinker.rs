@@ -2,7 +2,11 @@
 //! Thinker picks the right Action to run based on the resulting Scores.
 
 use crate::{
-    action::{Action, ActionCommands, ActionSpawn, ActionState},
+    action::{
+        Action, ActionCommands, ActionOutcome, ActionSpawn, ActionState, ActionStateQuery,
+        CancelReason,
+    },
+    history::{ActionTypes, ThinkerHistory},
     pickers::{Choice, ChoiceBuilder, FirstToScore, Highest, Picker},
     scorer::{Score, ScorerCommands, ScorerSpawn},
 };
@@ -10,13 +14,15 @@ use bevy_asset::{Asset, Assets, Handle};
 use bevy_ecs::{
     component::Component,
     entity::Entity,
+    event::{Event, EventWriter},
     query::Without,
     system::{Commands, Query, Res},
 };
 use bevy_hierarchy::{AddChild, DespawnRecursiveExt};
 use bevy_log as log;
 use bevy_reflect::{Reflect, TypePath};
-use std::{collections::VecDeque, sync::Arc};
+use bevy_time::Time;
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 
 /// Wrapper for Actor entities. In terms of Scorers, Thinkers, and Actions,
 /// this is the [`Entity`] actually _performing_ the action, rather than the
@@ -74,6 +80,8 @@ pub struct Thinker {
     current: Option<Action>,
     winner: Option<usize>,
     scheduled: VecDeque<Arc<dyn ActionSpawn>>,
+    retiring: bool,
+    pub(crate) history: ThinkerHistory,
 }
 
 impl Thinker {
@@ -88,15 +96,55 @@ impl Thinker {
     pub fn current(&self) -> Option<Action> {
         self.current
     }
+
+    pub(crate) fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+
+    /// When this Thinker's Actions finished.
+    pub fn history(&self) -> &ThinkerHistory {
+        &self.history
+    }
+}
+
+/// Event sent by a [`Thinker`] whenever its current Action finishes, right
+/// before the Action gets despawned.
+#[derive(Event, Debug, Clone)]
+pub struct ActionFinished {
+    /// The Actor entity.
+    pub actor: Entity,
+    /// The [`Thinker`] entity.
+    pub thinker: Entity,
+    /// The Action that finished.
+    pub action: Action,
+    /// Index of the [`ThinkerSpawner::when`] choice the Action was picked
+    /// for, or `None` for [scheduled](Thinker::schedule) Actions.
+    pub choice: Option<usize>,
+    /// Either [`ActionState::Success`] or [`ActionState::Failure`].
+    pub state: ActionState,
+    /// The [`ActionOutcome`] the Action finished with.
+    pub outcome: ActionOutcome,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn thinker_system(
     mut cmd: Commands,
-    mut query: Query<(&Actor, &mut Thinker)>,
+    mut query: Query<(Entity, &Actor, &mut Thinker)>,
     scores: Query<&Score>,
-    mut states: Query<&mut ActionState>,
+    mut states: Query<ActionStateQuery>,
+    outcomes: Query<&ActionOutcome>,
+    mut finished: EventWriter<ActionFinished>,
+    time: Option<Res<Time>>,
+    types: ActionTypes,
 ) {
-    for (&actor, mut thinker) in query.iter_mut() {
+    // Without a Time resource, the history is all recorded at zero.
+    let now = time.map_or(Duration::ZERO, |time| time.elapsed());
+    for (entity, &actor, mut thinker) in query.iter_mut() {
+        if thinker.retiring {
+            continue;
+        }
+        thinker.history.start(now);
+
         let next = thinker.picker.pick(&thinker.choices, &scores);
 
         if let Some(action) = thinker.current {
@@ -105,11 +153,11 @@ pub fn thinker_system(
                 ActionState::Executing => {
                     if thinker.has_scheduled() {
                         log::debug!("current {:?} cancel by scheduled", action);
-                        state.cancel();
+                        state.cancel(Some(CancelReason::Scheduled));
                     } else if let (Some(win), Some(next)) = (thinker.winner, next) {
                         if win != next {
                             log::debug!("current {:?} cancel by next", action);
-                            state.cancel();
+                            state.cancel(Some(CancelReason::Preempted));
                         }
                     }
                     continue;
@@ -117,6 +165,16 @@ pub fn thinker_system(
                 ActionState::Cancelled => continue,
                 ActionState::Success | ActionState::Failure => {
                     log::debug!("current {:?} is done, despawn", action);
+                    finished.send(ActionFinished {
+                        actor: actor.entity(),
+                        thinker: entity,
+                        action,
+                        choice: thinker.winner,
+                        state: (*state).clone(),
+                        outcome: outcomes.get(action.entity()).cloned().unwrap_or_default(),
+                    });
+                    let done = types.activities(action.entity(), thinker.winner);
+                    thinker.history.record(now, done);
                     cmd.queue(action.despawn_recursive());
                     thinker.current = None;
                     thinker.winner = None;
@@ -218,16 +276,16 @@ pub fn thinker_maintain_system(
             current: None,
             winner: None,
             scheduled: VecDeque::new(),
+            retiring: false,
+            history: ThinkerHistory::default(),
         };
 
         cmd.entity(parent).insert(thinker);
         cmd.entity(actor).insert(HasThinker(parent));
     }
 
-    for (actor, &HasThinker(thinker)) in without_handle.iter() {
-        if let Some(entity) = cmd.get_entity(thinker) {
-            entity.despawn_recursive();
-        }
+    // The Thinker itself gets cleaned up by `actor_gone_cleanup`.
+    for (actor, _) in without_handle.iter() {
         cmd.entity(actor).remove::<HasThinker>();
     }
 }
@@ -235,10 +293,40 @@ pub fn thinker_maintain_system(
 pub fn actor_gone_cleanup(
     mut cmd: Commands,
     builders: Query<&HandleThinkerSpawner>,
-    query: Query<(Entity, &Actor)>,
+    mut thinkers: Query<(Entity, &Actor, &mut Thinker)>,
+    query: Query<(Entity, &Actor), Without<Thinker>>,
+    mut states: Query<ActionStateQuery>,
 ) {
+    let mut retiring = Vec::new();
+
+    for (entity, actor, mut thinker) in thinkers.iter_mut() {
+        if !thinker.retiring && builders.get(actor.entity()).is_ok() {
+            continue;
+        }
+
+        // Actor is gone. Give the current Action a frame to react to being
+        // cancelled before cleaning up.
+        let current = thinker.current.map(|action| action.entity());
+        if let Some(mut state) = current.and_then(|action| states.get_mut(action).ok()) {
+            if !thinker.retiring && !state.is_done() {
+                log::debug!("{:?} lost its Actor, cancel {:?}", entity, current);
+                state.cancel_if_executing(Some(CancelReason::ActorGone));
+                thinker.retiring = true;
+                retiring.push(actor.entity());
+                continue;
+            }
+        }
+
+        if let Some(action) = thinker.current {
+            cmd.queue(action.despawn_recursive());
+        }
+        if let Some(entity) = cmd.get_entity(entity) {
+            entity.despawn_recursive();
+        }
+    }
+
     for (child, actor) in query.iter() {
-        if builders.get(actor.entity()).is_err() {
+        if builders.get(actor.entity()).is_err() && !retiring.contains(&actor.entity()) {
             // Actor is gone. Let's clean up.
             if let Some(entity) = cmd.get_entity(child) {
                 entity.despawn_recursive();
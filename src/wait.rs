@@ -0,0 +1,133 @@
+//! Built-in Action that waits for a while.
+
+use crate::action::{Action, ActionCommands, ActionQuery, ActionSpawn, ActionState};
+use bevy_ecs::{
+    component::Component,
+    system::{Query, Res},
+};
+use bevy_reflect::Reflect;
+use bevy_time::{Time, Timer, TimerMode};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// [`ActionSpawn`] for an Action that waits for a given amount of time, and
+/// then succeeds. Gets cancelled immediately, ending in
+/// [`ActionState::Failure`].
+///
+/// Time is measured with [`Time`], so the wait follows virtual time (and
+/// pauses with it) when actions run in a regular schedule, and fixed time when
+/// actions run in `FixedUpdate`.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::*;
+/// # #[derive(Debug, Clone, Component, ScorerSpawn)]
+/// # struct Bored;
+/// # #[derive(Debug, Clone, Component, ActionSpawn)]
+/// # struct LookAround;
+/// # fn main() {
+/// ThinkerSpawner::highest(0.0)
+///     .when(Bored, Sequence::step((LookAround, Wait::between(1.0, 3.0))))
+/// # ;
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Wait {
+    min: Duration,
+    max: Duration,
+    rng: Option<Arc<Mutex<SmallRng>>>,
+}
+
+impl Wait {
+    /// Wait for `duration`.
+    pub fn duration(duration: Duration) -> Self {
+        Self {
+            min: duration,
+            max: duration,
+            rng: None,
+        }
+    }
+
+    /// Wait for `secs` seconds. Negative and NaN values wait for no time at
+    /// all.
+    pub fn secs(secs: f32) -> Self {
+        Self::duration(from_secs(secs))
+    }
+
+    /// Wait for a random duration between `min` and `max` seconds, picked
+    /// every time the Action gets spawned. Negative and NaN values count as
+    /// `0.0`.
+    pub fn between(min: f32, max: f32) -> Self {
+        let (min, max) = (from_secs(min), from_secs(max));
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+            rng: None,
+        }
+    }
+
+    /// Picks the random durations of [`Wait::between`] with an RNG seeded
+    /// with `seed`, so the Actions spawned from this [`Wait`] wait for the
+    /// same series of durations on every run.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Some(Arc::new(Mutex::new(SmallRng::seed_from_u64(seed))));
+        self
+    }
+}
+
+/// Converts seconds to a [`Duration`], saturating instead of panicking.
+fn from_secs(secs: f32) -> Duration {
+    Duration::try_from_secs_f32(secs.max(0.0)).unwrap_or(Duration::MAX)
+}
+
+impl ActionSpawn for Wait {
+    fn spawn(&self, mut cmd: ActionCommands) -> Action {
+        let duration = if self.min == self.max {
+            self.min
+        } else if let Some(rng) = &self.rng {
+            let mut rng = rng.lock().unwrap();
+            rng.gen_range(self.min..=self.max)
+        } else {
+            SmallRng::from_entropy().gen_range(self.min..=self.max)
+        };
+
+        cmd.spawn(Waiting {
+            timer: Timer::new(duration, TimerMode::Once),
+        })
+    }
+}
+
+/// Action component spawned by [`Wait`].
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct Waiting {
+    timer: Timer,
+}
+
+impl Waiting {
+    /// Returns how much longer the Action is going to wait.
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+}
+
+pub fn wait_action_system(time: Res<Time>, mut query: Query<(ActionQuery, &mut Waiting)>) {
+    for (mut action, mut waiting) in query.iter_mut() {
+        match action.state() {
+            ActionState::Executing => {
+                waiting.timer.tick(time.delta());
+                action.set_progress(waiting.timer.fraction());
+                action.set_eta(Some(waiting.timer.remaining()));
+                if waiting.timer.finished() {
+                    action.success();
+                }
+            }
+            ActionState::Cancelled => action.failure(),
+            ActionState::Success | ActionState::Failure => (),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Resource)]
+struct Interest(f32);
+
+#[derive(Default, Resource)]
+struct Reasons(Vec<Option<CancelReason>>);
+
+#[derive(Component, Clone, ScorerSpawn)]
+struct Interested;
+
+fn interested_scorer(interest: Res<Interest>, mut query: Query<ScorerQuery, With<Interested>>) {
+    for mut score in query.iter_mut() {
+        score.set(interest.0);
+    }
+}
+
+/// Keeps executing until cancelled, and records why it got cancelled.
+#[derive(Component, Clone, ActionSpawn)]
+struct Hold;
+
+fn hold_action(mut reasons: ResMut<Reasons>, mut query: Query<ActionQuery, With<Hold>>) {
+    for mut action in query.iter_mut() {
+        if action.is_cancelled() {
+            reasons.0.push(action.cancel_reason());
+            action.failure();
+        }
+    }
+}
+
+fn app(thinker: ThinkerSpawner) -> (App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(Interest(1.0))
+        .init_resource::<Reasons>()
+        .add_systems(
+            Update,
+            (
+                interested_scorer.in_set(BigBrainSet::Scorers),
+                hold_action.in_set(BigBrainSet::Actions),
+            ),
+        );
+
+    let actor = common::spawn_thinker(&mut app, thinker).id();
+
+    common::update(&mut app, 5);
+    assert!(app.world().resource::<Reasons>().0.is_empty());
+
+    (app, actor)
+}
+
+fn first_reason(app: &mut App) -> Option<CancelReason> {
+    common::update(app, 5);
+    app.world().resource::<Reasons>().0[0]
+}
+
+#[test]
+fn preempted() {
+    let thinker = ThinkerSpawner::highest(0.0)
+        .when(Interested, Sequence::step(Hold))
+        .when(FixedScorer(0.5), Hold);
+    let (mut app, _) = app(thinker);
+
+    app.world_mut().resource_mut::<Interest>().0 = 0.0;
+    assert_eq!(first_reason(&mut app), Some(CancelReason::Preempted));
+}
+
+#[test]
+fn guard_dropped() {
+    let thinker = ThinkerSpawner::highest(0.0).when(
+        FixedScorer(1.0),
+        Sequence::step(Guard::build(Interested, 0.5, Hold)),
+    );
+    let (mut app, _) = app(thinker);
+
+    app.world_mut().resource_mut::<Interest>().0 = 0.0;
+    assert_eq!(first_reason(&mut app), Some(CancelReason::GuardDropped));
+}
+
+#[test]
+fn actor_gone() {
+    let thinker = ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), Sequence::step(Hold));
+    let (mut app, actor) = app(thinker);
+
+    app.world_mut()
+        .entity_mut(actor)
+        .remove::<HandleThinkerSpawner>();
+    assert_eq!(first_reason(&mut app), Some(CancelReason::ActorGone));
+
+    let actors = app.world_mut().query::<&Actor>().iter(app.world()).count();
+    assert_eq!(actors, 0);
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Component)]
+struct Thirst(f32);
+
+#[test]
+fn closures() {
+    let mut app = common::app();
+
+    let thinker = ThinkerSpawner::highest(0.5).when(
+        FnScorer::new(|actor, world| world.get::<Thirst>(actor).unwrap().0 / 100.0),
+        FnAction::new(|actor, state, world| {
+            let mut thirst = world.get_mut::<Thirst>(actor).unwrap();
+            thirst.0 -= 10.0;
+            match state {
+                ActionState::Executing if thirst.0 <= 0.0 => ActionStatus::Success,
+                ActionState::Executing => ActionStatus::Running,
+                _ => ActionStatus::Failure,
+            }
+        }),
+    );
+    let actor = common::spawn_thinker(&mut app, thinker)
+        .insert(Thirst(80.0))
+        .id();
+
+    common::update(&mut app, 20);
+
+    // Drinking goes on until the thirst is gone, and doesn't start over
+    // since the Scorer no longer beats the threshold.
+    assert_eq!(app.world().get::<Thirst>(actor).unwrap().0, 0.0);
+}
@@ -0,0 +1,71 @@
+//! Setup shared by the integration tests. Every test binary only uses part of
+//! it.
+#![allow(dead_code)]
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use big_brain::*;
+use std::time::Duration;
+
+/// An [`App`] running Scorers and Actions in `Update`, and Thinkers in
+/// `PostUpdate`.
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        BigBrainPlugin::new(Update, Update, PostUpdate, Last),
+    ));
+    app
+}
+
+/// Same as [`app`], but time advances by exactly `step` every frame.
+pub fn timed_app(step: Duration) -> App {
+    let mut app = app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(step));
+    app
+}
+
+/// Spawns an Actor thinking with `thinker`.
+pub fn spawn_thinker(app: &mut App, thinker: ThinkerSpawner) -> EntityWorldMut<'_> {
+    let thinker = app
+        .world_mut()
+        .resource_mut::<Assets<ThinkerSpawner>>()
+        .add(thinker);
+    app.world_mut().spawn(HandleThinkerSpawner(thinker))
+}
+
+/// Runs `frames` frames.
+pub fn update(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+/// Runs frames until `done` returns something, and panics if that takes
+/// more than `frames` frames.
+pub fn update_until<T>(
+    app: &mut App,
+    frames: usize,
+    mut done: impl FnMut(&mut App) -> Option<T>,
+) -> T {
+    for _ in 0..frames {
+        app.update();
+        if let Some(value) = done(app) {
+            return value;
+        }
+    }
+    panic!("still not done after {frames} frames");
+}
+
+/// Runs frames until the next [`ActionFinished`] event, for at most `frames`
+/// frames.
+pub fn next_finished(app: &mut App, frames: usize) -> ActionFinished {
+    let mut reader = app
+        .world()
+        .resource::<Events<ActionFinished>>()
+        .get_cursor_current();
+    update_until(app, frames, |app| {
+        let events = app.world().resource::<Events<ActionFinished>>();
+        reader.read(events).next().cloned()
+    })
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+/// Composite Scorer that returns the second best of its child Scores.
+#[derive(Clone, Component)]
+struct SecondBest;
+
+impl CompositeScorer for SecondBest {
+    fn combine(&self, children: &[f32]) -> f32 {
+        let mut children = children.to_vec();
+        children.sort_by(|a, b| b.total_cmp(a));
+        children.get(1).copied().unwrap_or(0.0)
+    }
+}
+
+fn score_of(scorer: impl ScorerSpawn + 'static) -> f32 {
+    let mut app = common::app();
+    app.register_composite_scorer::<SecondBest>();
+
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(2.0).when(scorer, Wait::secs(1.0)),
+    );
+    common::update(&mut app, 5);
+
+    // The composite is the only Scorer with children.
+    let mut query = app.world_mut().query_filtered::<&Score, With<Children>>();
+    query.single(app.world()).get()
+}
+
+#[test]
+fn composite() {
+    let children = || (FixedScorer(0.2), FixedScorer(0.6), FixedScorer(0.4));
+    assert_eq!(score_of(ScorerSpawner::new(SecondBest, children())), 0.4);
+    assert_eq!(score_of(WinningScorer::build(0.5, children())), 0.6);
+    assert_eq!(score_of(WinningScorer::build(0.7, children())), 0.0);
+    assert_eq!(score_of(AllOrNothing::build(0.3, children())), 0.0);
+    assert_eq!(score_of(SumOfScorers::build(0.3, children())), 1.0);
+}
+
+#[test]
+fn nested() {
+    let mut app = common::app();
+
+    let inner = |score| WinningScorer::build(0.0, (FixedScorer(score), FixedScorer(0.1)));
+    let nested = SumOfScorers::build(
+        0.0,
+        (
+            inner(0.1),
+            SumOfScorers::build(0.0, (inner(0.2), inner(0.3))),
+        ),
+    );
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(2.0).when(nested, Wait::secs(1.0)),
+    );
+
+    // The very first frame the leaf Scorers run, the outermost composite
+    // already combines their Scores.
+    let mut leaves = app
+        .world_mut()
+        .query_filtered::<&Score, (With<FixedScorer>, Without<Children>)>();
+    let mut outer = app
+        .world_mut()
+        .query_filtered::<(&Score, &Parent), With<Children>>();
+    let mut thinkers = app.world_mut().query::<&Thinker>();
+
+    common::update_until(&mut app, 10, |app| {
+        let scored = leaves.iter(app.world()).any(|score| score.get() > 0.0);
+        scored.then_some(())
+    });
+
+    let world = app.world();
+    let (score, _) = outer
+        .iter(world)
+        .find(|(_, parent)| thinkers.get(world, parent.get()).is_ok())
+        .unwrap();
+    assert!((score.get() - 0.6).abs() < 1e-6, "{}", score.get());
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Health(f32);
+
+#[derive(Component)]
+struct Enemy(Entity);
+
+fn score_of(consideration: Consideration, health: Option<f32>) -> f32 {
+    let mut app = common::app();
+    app.add_plugins(TransformPlugin).register_type::<Health>();
+
+    let enemy = app
+        .world_mut()
+        .spawn(Transform::from_xyz(3.0, 4.0, 0.0))
+        .id();
+    let thinker = ThinkerSpawner::highest(2.0).when(consideration, Wait::secs(1.0));
+    let mut actor = common::spawn_thinker(&mut app, thinker);
+    actor.insert((Transform::default(), Enemy(enemy)));
+    if let Some(health) = health {
+        actor.insert(Health(health));
+    }
+    common::update(&mut app, 3);
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&Score, With<Consideration>>();
+    query.single(app.world()).get()
+}
+
+#[test]
+fn pipeline() {
+    let hurt = Consideration::new(ConsiderationInput::field::<Health>("0"), 100.0, 0.0);
+    assert_eq!(hurt.score(25.0), 0.75);
+    assert_eq!(hurt.score(-10.0), 1.0);
+
+    let curved = hurt.clone().curve(Power::new(2.0, (0.0, 0.0), (1.0, 1.0)));
+    assert_eq!(curved.score(50.0), 0.25);
+
+    let clamped = curved.clamp(0.5, 1.0);
+    assert_eq!(clamped.score(100.0), 0.5);
+}
+
+#[test]
+fn field() {
+    let hurt = || Consideration::new(ConsiderationInput::field::<Health>("0"), 100.0, 0.0);
+    assert_eq!(score_of(hurt(), Some(25.0)), 0.75);
+    assert_eq!(score_of(hurt(), None), 0.0);
+}
+
+#[test]
+#[cfg(feature = "transform")]
+fn distance() {
+    let close = Consideration::new(
+        ConsiderationInput::distance(|actor, world| world.get::<Enemy>(actor).map(|enemy| enemy.0)),
+        10.0,
+        0.0,
+    );
+    assert_eq!(score_of(close, None), 0.5);
+}
+
+#[test]
+fn custom() {
+    let custom = Consideration::new(ConsiderationInput::custom(|_, _| Some(3.0)), 0.0, 4.0);
+    assert_eq!(score_of(custom, None), 0.75);
+    let missing =
+        Consideration::new(ConsiderationInput::custom(|_, _| None), 0.0, 4.0).clamp(0.2, 1.0);
+    assert_eq!(score_of(missing, None), 0.0);
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Needs {
+    thirst: f32,
+    stats: Stats,
+}
+
+#[derive(Reflect)]
+struct Stats {
+    fatigue: u8,
+    name: String,
+}
+
+#[derive(Component)]
+struct Unregistered;
+
+fn score_of(scorer: FieldScorer) -> f32 {
+    let mut app = common::app();
+    app.register_type::<Needs>();
+
+    let thinker = ThinkerSpawner::highest(2.0).when(scorer, Wait::secs(1.0));
+    common::spawn_thinker(&mut app, thinker).insert((
+        Needs {
+            thirst: 75.0,
+            stats: Stats {
+                fatigue: 200,
+                name: "Bob".into(),
+            },
+        },
+        Unregistered,
+    ));
+    common::update(&mut app, 3);
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&Score, With<FieldScorer>>();
+    query.single(app.world()).get()
+}
+
+#[test]
+fn field() {
+    assert_eq!(
+        score_of(FieldScorer::new::<Needs>("thirst", 0.0, 100.0)),
+        0.75
+    );
+    assert_eq!(
+        score_of(FieldScorer::new::<Needs>("thirst", 50.0, 60.0)),
+        1.0
+    );
+    let fatigue = FieldScorer::new::<Needs>("stats.fatigue", 0.0, 250.0);
+    assert_eq!(score_of(fatigue), 0.8);
+    let by_path = FieldScorer::from_type_path("field::Needs", "thirst", 0.0, 100.0);
+    assert_eq!(score_of(by_path), 0.75);
+}
+
+#[test]
+fn evaluator() {
+    let scorer = FieldScorer::new::<Needs>("thirst", 0.0, 100.0);
+    assert_eq!(score_of(scorer.evaluator(Power::default())), 0.5625);
+}
+
+#[test]
+fn misconfigured() {
+    assert_eq!(score_of(FieldScorer::new::<Needs>("hunger", 0.0, 1.0)), 0.0);
+    assert_eq!(
+        score_of(FieldScorer::new::<Needs>("stats.name", 0.0, 1.0)),
+        0.0
+    );
+    assert_eq!(
+        score_of(FieldScorer::new::<Unregistered>("0", 0.0, 1.0)),
+        0.0
+    );
+}
+
+#[test]
+#[should_panic]
+fn invalid_path() {
+    FieldScorer::new::<Needs>("stats..", 0.0, 1.0);
+}
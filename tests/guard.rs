@@ -0,0 +1,86 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Resource)]
+struct Visible(f32);
+
+#[derive(Default, Resource)]
+struct Outcomes(Vec<ActionState>);
+
+#[derive(Component, Clone, ScorerSpawn)]
+struct TargetVisible;
+
+fn target_visible_scorer(
+    visible: Res<Visible>,
+    mut query: Query<ScorerQuery, With<TargetVisible>>,
+) {
+    for mut score in query.iter_mut() {
+        score.set(visible.0);
+    }
+}
+
+/// Keeps executing until cancelled.
+#[derive(Component, Clone, ActionSpawn)]
+struct Chase;
+
+fn chase_action(mut query: Query<ActionQuery, With<Chase>>) {
+    for mut action in query.iter_mut() {
+        action.failure_if_cancelled();
+    }
+}
+
+fn record_outcomes(
+    mut outcomes: ResMut<Outcomes>,
+    query: Query<&ActionState, (With<Guard>, Changed<ActionState>)>,
+) {
+    for state in query.iter() {
+        if state.is_done() {
+            outcomes.0.push(state.clone());
+        }
+    }
+}
+
+fn app(guard: GuardSpawner) -> App {
+    let mut app = common::app();
+    app.insert_resource(Visible(1.0))
+        .init_resource::<Outcomes>()
+        .add_systems(
+            Update,
+            (
+                target_visible_scorer.in_set(BigBrainSet::Scorers),
+                chase_action.in_set(BigBrainSet::Actions),
+            ),
+        )
+        .add_systems(PostUpdate, record_outcomes.after(BigBrainSet::Sequence));
+
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), guard),
+    );
+    app
+}
+
+fn outcome_after_drop(guard: GuardSpawner) -> ActionState {
+    let mut app = app(guard);
+    common::update(&mut app, 10);
+    assert!(app.world().resource::<Outcomes>().0.is_empty());
+
+    app.world_mut().resource_mut::<Visible>().0 = 0.2;
+    common::update(&mut app, 10);
+
+    app.world().resource::<Outcomes>().0[0].clone()
+}
+
+#[test]
+fn fails_on_drop() {
+    let state = outcome_after_drop(Guard::build(TargetVisible, 0.3, Chase));
+    assert_eq!(state, ActionState::Failure);
+}
+
+#[test]
+fn succeeds_on_drop() {
+    let state = outcome_after_drop(Guard::build(TargetVisible, 0.3, Chase).succeed_on_drop());
+    assert_eq!(state, ActionState::Success);
+}
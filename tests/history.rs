@@ -0,0 +1,107 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Component, ActionSpawn)]
+struct Eat;
+
+#[derive(Debug, Clone, Component, ActionSpawn)]
+struct Nap;
+
+/// Succeeds right away.
+fn done_action_system<T: Component>(mut query: Query<ActionQuery, With<T>>) {
+    for mut action in query.iter_mut() {
+        action.success();
+    }
+}
+
+fn app(thinker: ThinkerSpawner) -> App {
+    let mut app = common::timed_app(Duration::from_millis(100));
+    app.configure_sets(Update, BigBrainSet::Scorers.after(BigBrainSet::Actions))
+        .add_systems(
+            Update,
+            (done_action_system::<Eat>, done_action_system::<Nap>).in_set(BigBrainSet::Actions),
+        );
+
+    common::spawn_thinker(&mut app, thinker);
+    app
+}
+
+/// Runs `frames` frames, and returns the elapsed time at every finished Eat.
+fn eats(app: &mut App, frames: usize) -> Vec<f32> {
+    let mut cursor = app
+        .world()
+        .resource::<Events<ActionFinished>>()
+        .get_cursor();
+    let mut eats = Vec::new();
+    for _ in 0..frames {
+        app.update();
+        let now = app.world().resource::<Time>().elapsed_secs();
+        let events = app.world().resource::<Events<ActionFinished>>();
+        eats.extend(cursor.read(events).map(|_| now));
+    }
+    eats
+}
+
+#[test]
+fn time_since() {
+    let urgency = TimeSinceScorer::choice(0, Linear::new((0.0, 0.0), (1.0, 1.0)));
+    let input = ConsiderationInput::time_since(Activity::Choice(0));
+    let considered = Consideration::new(input, 0.0, 1.0);
+    let thinkers = [
+        ThinkerSpawner::highest(0.5).when(urgency, Eat),
+        ThinkerSpawner::highest(0.5).when(considered, Eat),
+    ];
+
+    // Urgency grows from when the Thinker starts, and drops after every Eat.
+    for thinker in thinkers {
+        let mut app = app(thinker);
+        let eats = eats(&mut app, 40);
+        assert!(eats.len() >= 3, "{eats:?}");
+        for pair in eats.windows(2) {
+            assert!(pair[1] - pair[0] >= 0.5, "{eats:?}");
+        }
+    }
+}
+
+#[test]
+fn boredom() {
+    let bored = BoredomScorer::action::<Eat>(60.0, Linear::new((0.0, 1.0), (3.0, 0.0)));
+    let mut app = app(ThinkerSpawner::highest(0.2)
+        .when(bored, Eat)
+        .when(FixedScorer(0.1), Wait::secs(100.0)));
+
+    assert_eq!(eats(&mut app, 20).len(), 3);
+
+    let mut query = app.world_mut().query::<&Thinker>();
+    let history = query.single(app.world()).history();
+    let since = Duration::ZERO;
+    assert_eq!(history.count_since(Activity::Choice(0), since), 3);
+    assert_eq!(history.count_since(Activity::action::<Eat>(), since), 3);
+    assert_eq!(history.count_since(Activity::Choice(1), since), 0);
+}
+
+#[test]
+fn nested() {
+    let steps = Sequence::step((Eat, Nap));
+    let mut app = app(ThinkerSpawner::highest(0.5).when(FixedScorer(1.0), steps));
+    common::update(&mut app, 10);
+
+    let mut query = app.world_mut().query::<&Thinker>();
+    let history = query.single(app.world()).history();
+    let since = Duration::ZERO;
+    assert!(history.count_since(Activity::action::<Eat>(), since) >= 2);
+    assert!(history.count_since(Activity::action::<Nap>(), since) >= 2);
+    assert!(history.last(Activity::action::<Sequence>()).is_some());
+    assert_eq!(history.last(Activity::action::<Actor>()), None);
+    assert_eq!(history.last(Activity::action::<ActionState>()), None);
+}
+
+#[test]
+fn negative_window() {
+    let bored = BoredomScorer::action::<Eat>(-1.0, Linear::new((0.0, 1.0), (3.0, 0.0)));
+    let mut app = app(ThinkerSpawner::highest(0.5).when(bored, Eat));
+    assert!(eats(&mut app, 10).len() > 1);
+}
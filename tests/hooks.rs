@@ -0,0 +1,73 @@
+mod common;
+
+use bevy::{ecs::system::SystemParamItem, prelude::*};
+use big_brain::*;
+
+#[derive(Default, Resource)]
+struct Log(Vec<&'static str>);
+
+/// Succeeds after `.0` ticks, and takes a tick to wind down when cancelled.
+#[derive(Clone, Component, ActionSpawn)]
+struct Count(usize);
+
+impl ActionHooks for Count {
+    type Param = ResMut<'static, Log>;
+
+    fn on_start(&mut self, _: &mut ActionQueryItem, log: &mut SystemParamItem<Self::Param>) {
+        log.0.push("start");
+    }
+
+    fn on_tick(
+        &mut self,
+        _: &mut ActionQueryItem,
+        log: &mut SystemParamItem<Self::Param>,
+    ) -> ActionStatus {
+        log.0.push("tick");
+        self.0 -= 1;
+        if self.0 == 0 {
+            ActionStatus::Success
+        } else {
+            ActionStatus::Running
+        }
+    }
+
+    fn on_cancel(
+        &mut self,
+        _: &mut ActionQueryItem,
+        log: &mut SystemParamItem<Self::Param>,
+    ) -> ActionStatus {
+        log.0.push("cancel");
+        ActionStatus::Failure
+    }
+
+    fn on_finish(&mut self, action: &mut ActionQueryItem, log: &mut SystemParamItem<Self::Param>) {
+        log.0.push(if action.state() == ActionState::Success {
+            "success"
+        } else {
+            "failure"
+        });
+    }
+}
+
+fn run(thinker: ThinkerSpawner) -> Vec<&'static str> {
+    let mut app = common::app();
+    app.init_resource::<Log>().add_action_hooks::<Count>();
+
+    common::spawn_thinker(&mut app, thinker);
+    common::update(&mut app, 10);
+
+    std::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+}
+
+#[test]
+fn hooks() {
+    let log = run(ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), Count(3)));
+    assert_eq!(log[..5], ["start", "tick", "tick", "tick", "success"]);
+
+    let steps = Sequence::race((Count(1), Count(5)));
+    let log = run(ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), steps));
+    assert_eq!(
+        log[..7],
+        ["start", "tick", "success", "start", "tick", "cancel", "failure"]
+    );
+}
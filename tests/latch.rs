@@ -0,0 +1,52 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Resource)]
+struct Input(f32);
+
+#[derive(Debug, Clone, Component, ScorerSpawn)]
+struct Hunger;
+
+fn hunger_system(input: Res<Input>, mut query: Query<&mut Score, With<Hunger>>) {
+    for mut score in query.iter_mut() {
+        score.set(input.0);
+    }
+}
+
+fn app(scorer: impl ScorerSpawn + 'static) -> App {
+    let mut app = common::app();
+    app.insert_resource(Input(0.0))
+        .add_systems(Update, hunger_system.in_set(BigBrainSet::Scorers));
+
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(2.0).when(scorer, Wait::secs(1.0)),
+    );
+    app.update();
+    app
+}
+
+fn score(app: &mut App, input: f32) -> f32 {
+    app.world_mut().resource_mut::<Input>().0 = input;
+    app.update();
+    let mut query = app.world_mut().query_filtered::<&Score, With<Children>>();
+    query.single(app.world()).get()
+}
+
+#[test]
+fn latch() {
+    let mut app = app(LatchScorer::build(0.8, 0.2, Hunger));
+    let inputs = [0.5, 0.8, 0.5, 0.2, 0.1, 0.5, 0.9];
+    let latched = inputs.map(|input| score(&mut app, input));
+    assert_eq!(latched, [0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn inverted() {
+    let mut app = app(LatchScorer::inverted(0.8, 0.2, Hunger));
+    let inputs = [0.5, 0.9, 0.5, 0.1];
+    let latched = inputs.map(|input| score(&mut app, input));
+    assert_eq!(latched, [1.0, 0.0, 0.0, 1.0]);
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use bevy::{ecs::query::QueryFilter, prelude::*};
+use big_brain::*;
+
+#[derive(Component)]
+struct Stunned;
+
+#[derive(Component)]
+struct Carrying;
+
+fn spawn(scorer: impl ScorerSpawn + 'static) -> (App, Entity) {
+    let mut app = common::app();
+
+    let actor = common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(2.0).when(scorer, Wait::secs(1.0)),
+    )
+    .id();
+    (app, actor)
+}
+
+/// Runs a couple of frames and returns the only Score matching `F`.
+fn score<F: QueryFilter>(app: &mut App) -> f32 {
+    common::update(app, 2);
+    let mut query = app.world_mut().query_filtered::<&Score, F>();
+    query.single(app.world()).get()
+}
+
+#[test]
+fn has_and_lacks() {
+    let (mut app, actor) = spawn(MatchScorer::has::<Stunned>());
+    assert_eq!(score::<With<MatchScorer>>(&mut app), 0.0);
+    app.world_mut().entity_mut(actor).insert(Stunned);
+    assert_eq!(score::<With<MatchScorer>>(&mut app), 1.0);
+
+    let (mut app, actor) = spawn(MatchScorer::lacks::<Stunned>().values(0.8, 0.1));
+    assert_eq!(score::<With<MatchScorer>>(&mut app), 0.8);
+    app.world_mut().entity_mut(actor).insert(Stunned);
+    assert_eq!(score::<With<MatchScorer>>(&mut app), 0.1);
+}
+
+#[test]
+fn gate() {
+    let gate = AllOrNothing::build(
+        0.5,
+        (
+            MatchScorer::filter::<(With<Carrying>, Without<Stunned>)>(),
+            FixedScorer(0.6),
+        ),
+    );
+    let (mut app, actor) = spawn(gate);
+    assert_eq!(score::<With<Children>>(&mut app), 0.0);
+    app.world_mut().entity_mut(actor).insert(Carrying);
+    assert_eq!(score::<With<Children>>(&mut app), 1.0);
+    app.world_mut().entity_mut(actor).insert(Stunned);
+    assert_eq!(score::<With<Children>>(&mut app), 0.0);
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use bevy::{log::tracing_subscriber, prelude::*};
+use big_brain::*;
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+};
+
+#[derive(Default, Resource)]
+struct Drinks(usize);
+
+#[derive(Clone, Component, Reflect, ScorerSpawn)]
+struct Thirsty;
+
+fn thirsty_scorer(mut query: Query<ScorerQuery, With<Thirsty>>) {
+    for mut score in query.iter_mut() {
+        score.set(1.0);
+    }
+}
+
+#[derive(Clone, Component, Reflect, ActionSpawn)]
+struct Drink;
+
+fn drink_action(mut drinks: ResMut<Drinks>, mut query: Query<ActionQuery, With<Drink>>) {
+    for mut action in query.iter_mut() {
+        if action.is_executing() {
+            drinks.0 += 1;
+            action.success();
+        }
+    }
+}
+
+/// Never gets a system registered.
+#[derive(Clone, Component, ActionSpawn)]
+struct Forgotten;
+
+/// Collects everything that gets logged.
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn register() {
+    let mut app = common::app();
+    app.init_resource::<Drinks>()
+        .register_scorer::<Thirsty, _>(thirsty_scorer)
+        .register_action::<Drink, _>(drink_action);
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.contains(std::any::TypeId::of::<Thirsty>()));
+    assert!(registry.contains(std::any::TypeId::of::<Drink>()));
+    drop(registry);
+
+    let thinker =
+        ThinkerSpawner::highest(0.0).when(Thirsty, Sequence::step((Drink, Wait::secs(0.0))));
+    common::spawn_thinker(&mut app, thinker);
+
+    common::update(&mut app, 10);
+    assert!(app.world().resource::<Drinks>().0 > 1);
+}
+
+#[test]
+fn unregistered() {
+    let logs = Logs::default();
+    let writer = logs.clone();
+    tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .init();
+
+    let mut app = common::app();
+    app.init_resource::<Drinks>()
+        .register_scorer::<Thirsty, _>(thirsty_scorer)
+        .register_action::<Drink, _>(drink_action);
+
+    let thinker = ThinkerSpawner::highest(0.0).when(Thirsty, Sequence::step((Drink, Forgotten)));
+    common::spawn_thinker(&mut app, thinker);
+
+    common::update(&mut app, 10);
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    let warnings = logs
+        .lines()
+        .filter(|line| line.contains("has no registered system"))
+        .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1, "{logs}");
+    assert!(warnings[0].contains("Action"));
+    assert!(warnings[0].contains("Forgotten"));
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Resource)]
+struct Raw(f32);
+
+#[derive(Clone, Component, ScorerSpawn)]
+struct Broken;
+
+fn broken_scorer(raw: Res<Raw>, mut query: Query<ScorerQuery, With<Broken>>) {
+    for mut score in query.iter_mut() {
+        score.set(raw.0);
+    }
+}
+
+/// Scores `1.5` on purpose, so it opts out of the [`ScorePolicy`].
+#[derive(Clone, Component)]
+struct Unbounded;
+
+impl ScorerSpawn for Unbounded {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn((self.clone(), ScorePolicy::Unchecked))
+    }
+}
+
+fn unbounded_scorer(mut query: Query<&mut Score, With<Unbounded>>) {
+    for mut score in query.iter_mut() {
+        score.set_unchecked(1.5);
+    }
+}
+
+/// Scores a [`Broken`] Scorer producing `raw`, both on its own and nested in
+/// a composite.
+fn scores(policy: ScorePolicy, raw: f32) -> (f32, f32) {
+    let mut app = common::app();
+    app.insert_resource(policy)
+        .insert_resource(Raw(raw))
+        .add_systems(Update, broken_scorer.in_set(BigBrainSet::Scorers));
+
+    let thinker = ThinkerSpawner::highest(2.0)
+        .when(Broken, Wait::secs(1.0))
+        .when(WinningScorer::build(0.0, Broken), Wait::secs(1.0));
+    common::spawn_thinker(&mut app, thinker);
+    common::update(&mut app, 5);
+
+    let mut leaves = app.world_mut().query_filtered::<&Score, With<Broken>>();
+    let leaf = leaves.iter(app.world()).next().unwrap().get();
+    let mut composite = app
+        .world_mut()
+        .query_filtered::<&Score, With<WinningScorer>>();
+    (leaf, composite.single(app.world()).get())
+}
+
+#[test]
+fn clamp() {
+    assert_eq!(scores(ScorePolicy::Clamp, 1.5), (1.0, 1.0));
+    assert_eq!(scores(ScorePolicy::Clamp, f32::NAN), (0.0, 0.0));
+    assert_eq!(scores(ScorePolicy::ClampAndWarnOnce, -0.5), (0.0, 0.0));
+    assert_eq!(scores(ScorePolicy::NanAsZero, f32::NAN), (0.0, 0.0));
+    assert_eq!(scores(ScorePolicy::Unchecked, 1.5).0, 1.5);
+}
+
+#[test]
+#[should_panic(expected = "score_policy::Broken")]
+fn panic() {
+    scores(ScorePolicy::Panic, 1.5);
+}
+
+#[test]
+#[should_panic(expected = "isn't within 0.0..=1.0")]
+fn nan_as_zero_panics_out_of_range() {
+    scores(ScorePolicy::NanAsZero, 1.5);
+}
+
+#[test]
+fn set_unchecked() {
+    let mut app = common::app();
+    app.add_systems(Update, unbounded_scorer.in_set(BigBrainSet::Scorers));
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(2.0).when(Unbounded, Wait::secs(1.0)),
+    );
+    common::update(&mut app, 5);
+
+    let mut query = app.world_mut().query_filtered::<&Score, With<Unbounded>>();
+    assert_eq!(query.single(app.world()).get(), 1.5);
+}
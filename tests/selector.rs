@@ -0,0 +1,115 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Default, Resource)]
+struct Log(Vec<usize>);
+
+#[derive(Default, Resource)]
+struct Level(f32);
+
+#[derive(Component, Clone, ScorerSpawn)]
+struct Leveled;
+
+fn leveled_scorer(level: Res<Level>, mut query: Query<ScorerQuery, With<Leveled>>) {
+    for mut score in query.iter_mut() {
+        score.set(level.0);
+    }
+}
+
+/// Logs its label when it starts, and keeps executing until cancelled (or
+/// succeeds right away, if `.1` is set).
+#[derive(Component, Clone, ActionSpawn)]
+struct Task(usize, bool);
+
+fn task_action(mut log: ResMut<Log>, mut query: Query<(ActionQuery, Ref<Task>)>) {
+    for (mut action, task) in query.iter_mut() {
+        if task.is_added() {
+            log.0.push(task.0);
+        }
+        match action.state() {
+            ActionState::Executing if task.1 => action.success(),
+            ActionState::Cancelled => action.failure(),
+            _ => (),
+        }
+    }
+}
+
+fn run(level: f32, selector: SelectorSpawner, then: f32) -> Vec<usize> {
+    let mut app = common::app();
+    app.insert_resource(Level(level))
+        .init_resource::<Log>()
+        .add_systems(
+            Update,
+            (
+                leveled_scorer.in_set(BigBrainSet::Scorers),
+                task_action.in_set(BigBrainSet::Actions),
+            ),
+        );
+
+    let steps = Sequence::step((selector, Task(9, true)));
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), steps),
+    );
+
+    common::update(&mut app, 10);
+    app.world_mut().resource_mut::<Level>().0 = then;
+    common::update(&mut app, 10);
+
+    std::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+}
+
+#[test]
+fn picks_once() {
+    let selector = || {
+        Selector::highest(0.0)
+            .when(FixedScorer(0.5), Task(0, true))
+            .when(Leveled, Task(1, true))
+    };
+    assert_eq!(run(0.2, selector(), 0.2)[..2], [0, 9]);
+    assert_eq!(run(0.8, selector(), 0.8)[..2], [1, 9]);
+}
+
+#[test]
+fn reevaluate() {
+    let selector = || {
+        Selector::highest(0.0)
+            .when(FixedScorer(0.5), Task(0, false))
+            .when(Leveled, Task(1, false))
+    };
+    assert_eq!(run(0.2, selector(), 0.8), [0]);
+    assert_eq!(run(0.2, selector().reevaluate(), 0.8), [0, 1]);
+}
+
+#[test]
+fn cancelled_before_picking() {
+    let mut app = common::app();
+    app.insert_resource(Level(1.0))
+        .init_resource::<Log>()
+        .add_systems(
+            Update,
+            (
+                leveled_scorer.in_set(BigBrainSet::Scorers),
+                task_action.in_set(BigBrainSet::Actions),
+            ),
+        );
+
+    let selector = Selector::highest(0.0).when(FixedScorer(0.5), Task(0, true));
+    let thinker = ThinkerSpawner::highest(0.0)
+        .when(Leveled, selector)
+        .when(FixedScorer(0.5), Task(9, false));
+    common::spawn_thinker(&mut app, thinker);
+
+    // The Thinker switches away as soon as the Selector is spawned, so it gets
+    // cancelled before it had a chance to pick.
+    common::update_until(&mut app, 10, |app| {
+        let mut selectors = app.world_mut().query::<&Selector>();
+        selectors.iter(app.world()).next().map(|_| ())
+    });
+    app.world_mut().resource_mut::<Level>().0 = 0.0;
+    common::update(&mut app, 10);
+
+    assert_eq!(app.world().resource::<Log>().0, [9]);
+}
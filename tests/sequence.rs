@@ -0,0 +1,328 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Default, Resource)]
+struct Log(Vec<usize>);
+
+#[derive(Default, Resource)]
+struct Outcomes(Vec<(ActionState, StepSummary)>);
+
+#[derive(Default, Resource)]
+struct Gates {
+    open: bool,
+    interrupt: f32,
+}
+
+/// Logs its label and succeeds.
+#[derive(Component, Clone, ActionSpawn)]
+struct Record(usize);
+
+/// Logs its label and fails.
+#[derive(Component, Clone, ActionSpawn)]
+struct Fail(usize);
+
+#[derive(Debug, PartialEq)]
+struct Blocked(usize);
+
+/// Fails with a [`Blocked`] reason.
+#[derive(Component, Clone, ActionSpawn)]
+struct Block(usize);
+
+/// Keeps executing until cancelled.
+#[derive(Component, Clone, ActionSpawn)]
+struct Hold;
+
+/// Logs its label when it starts, and succeeds once the gates are open.
+#[derive(Component, Clone, ActionSpawn)]
+struct Gate(usize);
+
+/// Logs its label when it starts, and succeeds once cancelled.
+#[derive(Component, Clone, ActionSpawn)]
+struct Finish(usize);
+
+#[derive(Component, Clone, ScorerSpawn)]
+struct Interrupt;
+
+fn record_action(mut log: ResMut<Log>, mut query: Query<(ActionQuery, &Record)>) {
+    for (mut action, &Record(label)) in query.iter_mut() {
+        match action.state() {
+            ActionState::Executing => {
+                log.0.push(label);
+                action.success();
+            }
+            ActionState::Cancelled => action.failure(),
+            ActionState::Success | ActionState::Failure => (),
+        }
+    }
+}
+
+fn fail_action(mut log: ResMut<Log>, mut query: Query<(ActionQuery, &Fail)>) {
+    for (mut action, &Fail(label)) in query.iter_mut() {
+        if action.is_executing() || action.is_cancelled() {
+            log.0.push(label);
+            action.failure();
+        }
+    }
+}
+
+fn block_action(mut query: Query<(ActionQuery, &Block)>) {
+    for (mut action, &Block(label)) in query.iter_mut() {
+        if action.is_executing() {
+            action.failure_with(Blocked(label));
+        }
+    }
+}
+
+fn hold_action(mut query: Query<ActionQuery, With<Hold>>) {
+    for mut action in query.iter_mut() {
+        action.failure_if_cancelled();
+    }
+}
+
+fn gate_action(
+    gates: Res<Gates>,
+    mut log: ResMut<Log>,
+    mut query: Query<(ActionQuery, Ref<Gate>)>,
+) {
+    for (mut action, gate) in query.iter_mut() {
+        if gate.is_added() {
+            log.0.push(gate.0);
+        }
+        match action.state() {
+            ActionState::Executing if gates.open => action.success(),
+            ActionState::Cancelled => action.failure(),
+            _ => (),
+        }
+    }
+}
+
+fn finish_action(mut log: ResMut<Log>, mut query: Query<(ActionQuery, Ref<Finish>)>) {
+    for (mut action, finish) in query.iter_mut() {
+        if finish.is_added() {
+            log.0.push(finish.0);
+        }
+        if action.is_cancelled() {
+            action.success();
+        }
+    }
+}
+
+fn interrupt_scorer(gates: Res<Gates>, mut query: Query<ScorerQuery, With<Interrupt>>) {
+    for mut score in query.iter_mut() {
+        score.set(gates.interrupt);
+    }
+}
+
+fn record_outcomes(
+    mut outcomes: ResMut<Outcomes>,
+    query: Query<(&ActionState, &Sequence), Changed<ActionState>>,
+) {
+    for (state, sequence) in query.iter() {
+        if state.is_done() {
+            outcomes.0.push((state.clone(), sequence.summary()));
+        }
+    }
+}
+
+fn app(steps: SequenceSpawner) -> App {
+    thinker_app(ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), steps))
+}
+
+fn thinker_app(thinker: ThinkerSpawner) -> App {
+    let mut app = common::app();
+    app.init_resource::<Log>()
+        .init_resource::<Outcomes>()
+        .init_resource::<Gates>()
+        .add_systems(
+            Update,
+            (
+                interrupt_scorer.in_set(BigBrainSet::Scorers),
+                (
+                    record_action,
+                    fail_action,
+                    block_action,
+                    hold_action,
+                    gate_action,
+                    finish_action,
+                )
+                    .in_set(BigBrainSet::Actions),
+            ),
+        )
+        .add_systems(PostUpdate, record_outcomes.after(BigBrainSet::Sequence));
+
+    common::spawn_thinker(&mut app, thinker);
+    app
+}
+
+fn run(steps: SequenceSpawner, frames: usize) -> Vec<usize> {
+    let mut app = app(steps);
+    common::update(&mut app, frames);
+
+    std::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+}
+
+fn first_outcome(steps: SequenceSpawner) -> (ActionState, StepSummary) {
+    let mut app = app(steps);
+    common::update_until(&mut app, 30, |app| {
+        app.world().resource::<Outcomes>().0.first().cloned()
+    })
+}
+
+#[test]
+fn repeat() {
+    let log = run(
+        Sequence::step((Record(0), Record(1), Record(2))).repeat(2),
+        30,
+    );
+    // The thinker picks the sequence again once it succeeds, so only look at
+    // the first spawned sequence.
+    assert_eq!(log[..6], [0, 1, 2, 0, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn repeat_zero() {
+    Sequence::step((Record(0), Record(1))).repeat(0);
+}
+
+#[test]
+fn shuffle_with_seed() {
+    let steps = || Sequence::step((Record(0), Record(1), Record(2), Record(3)));
+    let log = run(steps().shuffle_with_seed(42).forever(), 40);
+    assert!(log.len() >= 8);
+
+    for pass in log.chunks_exact(4) {
+        let mut pass = pass.to_vec();
+        pass.sort();
+        assert_eq!(pass, [0, 1, 2, 3]);
+    }
+
+    assert_eq!(log, run(steps().shuffle_with_seed(42).forever(), 40));
+}
+
+#[test]
+fn abort_on_failure() {
+    let steps = || Sequence::step((Record(0), Fail(1), Record(2)));
+    assert_eq!(run(steps(), 30)[..4], [0, 1, 0, 1]);
+
+    let (state, summary) = first_outcome(steps());
+    assert_eq!(state, ActionState::Failure);
+    assert_eq!(summary.succeeded, 1);
+    assert_eq!(summary.failed, 1);
+}
+
+#[test]
+fn skip_failures() {
+    let steps = || Sequence::step((Record(0), Fail(1), Record(2), Fail(3)));
+    let skip = || steps().on_step_failure(StepFailurePolicy::Skip);
+    assert_eq!(run(skip(), 30)[..4], [0, 1, 2, 3]);
+
+    let (state, summary) = first_outcome(skip());
+    assert_eq!(state, ActionState::Success);
+    assert_eq!(summary.succeeded, 2);
+    assert_eq!(summary.failed, 2);
+
+    let policy = StepFailurePolicy::SkipThenFail;
+    let (state, _) = first_outcome(steps().on_step_failure(policy));
+    assert_eq!(state, ActionState::Failure);
+
+    let policy = StepFailurePolicy::AtLeast(2);
+    let (state, _) = first_outcome(steps().on_step_failure(policy));
+    assert_eq!(state, ActionState::Success);
+
+    let policy = StepFailurePolicy::AtLeast(3);
+    let (state, _) = first_outcome(steps().on_step_failure(policy));
+    assert_eq!(state, ActionState::Failure);
+}
+
+#[test]
+fn quorum() {
+    let (state, _) = first_outcome(Sequence::quorum(2, (Record(0), Fail(1), Record(2))));
+    assert_eq!(state, ActionState::Success);
+
+    let (state, _) = first_outcome(Sequence::quorum(2, (Record(0), Fail(1), Fail(2))));
+    assert_eq!(state, ActionState::Failure);
+
+    let steps = Sequence::quorum(1, (Fail(0), Hold)).max_failures(0);
+    let (state, _) = first_outcome(steps);
+    assert_eq!(state, ActionState::Failure);
+}
+
+#[test]
+fn failure_reason() {
+    let mut app = app(Sequence::step((Record(0), Block(1), Record(2))));
+    let event = common::next_finished(&mut app, 10);
+    assert_eq!(event.state, ActionState::Failure);
+    assert_eq!(event.choice, Some(0));
+    assert_eq!(event.outcome.get::<Blocked>(), Some(&Blocked(1)));
+}
+
+#[test]
+fn skipped_failures_summary() {
+    let steps = Sequence::step((Record(0), Fail(1), Record(2), Fail(3)))
+        .on_step_failure(StepFailurePolicy::SkipThenFail);
+    let mut app = app(steps);
+    let event = common::next_finished(&mut app, 10);
+    assert_eq!(event.state, ActionState::Failure);
+    let summary = event.outcome.get::<StepSummary>().unwrap();
+    assert_eq!((summary.succeeded, summary.failed), (2, 2));
+}
+
+fn interrupted(steps: SequenceSpawner) -> Vec<usize> {
+    let mut app = thinker_app(
+        ThinkerSpawner::highest(0.0)
+            .when(FixedScorer(0.5), steps)
+            .when(Interrupt, Hold),
+    );
+
+    common::update(&mut app, 10);
+    app.world_mut().resource_mut::<Gates>().interrupt = 1.0;
+    common::update(&mut app, 10);
+    *app.world_mut().resource_mut::<Gates>() = Gates {
+        open: true,
+        interrupt: 0.0,
+    };
+    common::update(&mut app, 10);
+
+    std::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+}
+
+#[test]
+fn resumable() {
+    let steps = || Sequence::step((Record(0), Record(1), Gate(2), Record(3)));
+    assert_eq!(interrupted(steps())[..7], [0, 1, 2, 0, 1, 2, 3]);
+    assert_eq!(interrupted(steps().resumable())[..5], [0, 1, 2, 2, 3]);
+
+    // Steps that still succeed when interrupted don't run again.
+    let steps = Sequence::step((Record(0), Finish(1), Record(2))).resumable();
+    assert_eq!(interrupted(steps)[..3], [0, 1, 2]);
+}
+
+#[test]
+fn finally_runs_cleanup() {
+    let mut app = thinker_app(
+        ThinkerSpawner::highest(0.0)
+            .when(FixedScorer(0.5), Finally::build(Gate(0), Gate(1)))
+            .when(Interrupt, Hold),
+    );
+    let mut reader = app
+        .world_mut()
+        .resource_mut::<Events<ActionFinished>>()
+        .get_cursor();
+    common::update(&mut app, 10);
+    assert_eq!(app.world().resource::<Log>().0, [0]);
+
+    // Cancelling the action starts the cleanup, which the thinker waits for.
+    app.world_mut().resource_mut::<Gates>().interrupt = 1.0;
+    common::update(&mut app, 10);
+    assert_eq!(app.world().resource::<Log>().0, [0, 1]);
+    let events = app.world().resource::<Events<ActionFinished>>();
+    assert_eq!(reader.read(events).count(), 0);
+
+    app.world_mut().resource_mut::<Gates>().open = true;
+    let event = common::next_finished(&mut app, 10);
+    assert_eq!(event.state, ActionState::Failure);
+    assert_eq!(event.choice, Some(0));
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+use std::time::Duration;
+
+#[derive(Resource)]
+struct Input(f32);
+
+#[derive(Debug, Clone, Component, ScorerSpawn)]
+struct Perception;
+
+fn perception_system(input: Res<Input>, mut query: Query<&mut Score, With<Perception>>) {
+    for mut score in query.iter_mut() {
+        score.set(input.0);
+    }
+}
+
+fn app(scorer: impl ScorerSpawn + 'static, step: f32) -> App {
+    let mut app = common::timed_app(Duration::from_secs_f32(step));
+    app.insert_resource(Input(0.0))
+        .add_systems(Update, perception_system.in_set(BigBrainSet::Scorers));
+
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(2.0).when(scorer, Wait::secs(1.0)),
+    );
+    common::update(&mut app, 3);
+    app
+}
+
+fn smoothed(app: &mut App) -> f32 {
+    let mut query = app.world_mut().query_filtered::<&Score, With<Children>>();
+    query.single(app.world()).get()
+}
+
+/// Sets the input to `input` and runs `secs` seconds worth of frames.
+fn run(app: &mut App, input: f32, secs: f32, step: f32) -> f32 {
+    app.world_mut().resource_mut::<Input>().0 = input;
+    common::update(app, (secs / step).round() as usize);
+    smoothed(app)
+}
+
+#[test]
+fn ema_is_frame_rate_independent() {
+    for step in [0.1, 0.01] {
+        let mut app = app(SmoothedScorer::ema(1.0, Perception), step);
+        assert_eq!(smoothed(&mut app), 0.0);
+        let score = run(&mut app, 1.0, 1.0, step);
+        assert!((score - 0.5).abs() < 0.01, "{step}: {score}");
+        let score = run(&mut app, 1.0, 1.0, step);
+        assert!((score - 0.75).abs() < 0.01, "{step}: {score}");
+    }
+}
+
+#[test]
+fn attack_release() {
+    let step = 0.05;
+    let mut app = app(SmoothedScorer::attack_release(0.0, 1.0, Perception), step);
+    assert_eq!(run(&mut app, 1.0, step, step), 1.0);
+    let score = run(&mut app, 0.0, 1.0, step);
+    assert!((score - 0.5).abs() < 0.01, "{score}");
+}
+
+#[test]
+fn max_rate() {
+    for step in [0.1, 0.02] {
+        let mut app = app(SmoothedScorer::max_rate(0.5, Perception), step);
+        let score = run(&mut app, 1.0, 1.0, step);
+        assert!((score - 0.5).abs() < 0.01, "{step}: {score}");
+        let score = run(&mut app, 1.0, 2.0, step);
+        assert!((score - 1.0).abs() < 0.01, "{step}: {score}");
+    }
+}
+
+#[test]
+fn hold() {
+    let step = 0.1;
+    let mut app = app(SmoothedScorer::hold(1.0, Perception), step);
+    assert_eq!(run(&mut app, 0.8, step, step), 0.8);
+    assert_eq!(run(&mut app, 0.2, 0.5, step), 0.8);
+    assert_eq!(run(&mut app, 0.2, 0.6, step), 0.2);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Component)]
+struct Health(f32);
+
+#[derive(Default, Resource)]
+struct Starts(Vec<f32>);
+
+/// Scores how much health the actor lost since the Scorer was spawned.
+#[derive(Clone)]
+struct Hurt;
+
+#[derive(Component)]
+struct HurtSince(f32);
+
+impl ScorerSpawn for Hurt {
+    fn spawn(&self, mut cmd: ScorerCommands) -> Scorer {
+        cmd.spawn_with(|actor| HurtSince(actor.get::<Health>().unwrap().0))
+    }
+}
+
+fn hurt_scorer(healths: Query<&Health>, mut query: Query<(ScorerQuery, &HurtSince)>) {
+    for (mut score, &HurtSince(start)) in query.iter_mut() {
+        let health = healths.get(score.actor()).unwrap();
+        score.set(1.0 - health.0 / start);
+    }
+}
+
+/// Succeeds once the actor lost 20% of the health it had when it started.
+#[derive(Clone)]
+struct LoseHealth;
+
+#[derive(Component)]
+struct LosingHealth {
+    start: f32,
+}
+
+impl ActionSpawn for LoseHealth {
+    fn spawn(&self, mut cmd: ActionCommands) -> Action {
+        cmd.spawn_with(|actor| LosingHealth {
+            start: actor.get::<Health>().unwrap().0,
+        })
+    }
+}
+
+fn lose_health_action(
+    mut starts: ResMut<Starts>,
+    mut healths: Query<&mut Health>,
+    mut query: Query<(ActionQuery, Ref<LosingHealth>)>,
+) {
+    for (mut action, losing) in query.iter_mut() {
+        if losing.is_added() {
+            starts.0.push(losing.start);
+        }
+        let mut health = healths.get_mut(action.actor()).unwrap();
+        health.0 -= 5.0;
+        if health.0 <= losing.start * 0.8 {
+            action.success();
+        }
+    }
+}
+
+#[test]
+fn spawn_with() {
+    let mut app = common::app();
+    app.init_resource::<Starts>().add_systems(
+        Update,
+        (
+            hurt_scorer.in_set(BigBrainSet::Scorers),
+            lose_health_action.in_set(BigBrainSet::Actions),
+        ),
+    );
+
+    let thinker = ThinkerSpawner::highest(0.0)
+        .when(FixedScorer(1.0), LoseHealth)
+        .when(Hurt, LoseHealth);
+    common::spawn_thinker(&mut app, thinker).insert(Health(50.0));
+
+    common::update(&mut app, 10);
+
+    // The actor loses 5.0 health per frame, so every Action succeeds once it
+    // lost 10.0, and the next one starts out from there.
+    assert_eq!(app.world().resource::<Starts>().0[..3], [50.0, 40.0, 30.0]);
+
+    let mut query = app.world_mut().query::<(&Score, &HurtSince)>();
+    let (score, since) = query.single(app.world());
+    assert_eq!(since.0, 50.0);
+    assert!(score.get() >= 0.2, "{}", score.get());
+}
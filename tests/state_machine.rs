@@ -0,0 +1,114 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+
+#[derive(Default, Resource)]
+struct Log(Vec<&'static str>);
+
+#[derive(Default, Resource)]
+struct Alarm(f32);
+
+#[derive(Component, Clone, ScorerSpawn)]
+struct Intruder;
+
+fn intruder_scorer(alarm: Res<Alarm>, mut query: Query<ScorerQuery, With<Intruder>>) {
+    for mut score in query.iter_mut() {
+        score.set(alarm.0);
+    }
+}
+
+/// Logs its label when it starts, and ends with the given result right away
+/// (or keeps executing until cancelled, when there's no result).
+#[derive(Component, Clone, ActionSpawn)]
+struct Step(&'static str, Option<bool>);
+
+fn step_action(mut log: ResMut<Log>, mut query: Query<(ActionQuery, Ref<Step>)>) {
+    for (mut action, step) in query.iter_mut() {
+        if step.is_added() {
+            log.0.push(step.0);
+        }
+        match (action.state(), step.1) {
+            (ActionState::Executing, None) => action.set_progress(0.5),
+            (ActionState::Executing, Some(true)) => action.success(),
+            (ActionState::Executing, Some(false)) | (ActionState::Cancelled, _) => action.failure(),
+            _ => (),
+        }
+    }
+}
+
+fn machine_app(machine: StateMachineSpawner) -> App {
+    let mut app = common::app();
+    app.init_resource::<Log>()
+        .init_resource::<Alarm>()
+        .add_systems(
+            Update,
+            (
+                intruder_scorer.in_set(BigBrainSet::Scorers),
+                step_action.in_set(BigBrainSet::Actions),
+            ),
+        );
+
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), machine),
+    );
+    app
+}
+
+#[test]
+fn state_machine() {
+    let mut app = machine_app(
+        StateMachine::build("patrol")
+            .state("patrol", Step("patrol", None))
+            .state("challenge", Step("challenge", Some(false)))
+            .state("alarm", Step("alarm", Some(true)))
+            .when("patrol", Intruder, 0.5, "challenge")
+            .on_failure("challenge", "alarm"),
+    );
+
+    common::update(&mut app, 10);
+    let mut query = app.world_mut().query::<(&StateMachine, &ActionProgress)>();
+    let (machine, progress) = query.single(app.world());
+    assert_eq!(machine.state(), "patrol");
+    assert_eq!(progress.get(), 0.5);
+
+    app.world_mut().resource_mut::<Alarm>().0 = 1.0;
+    let event = common::next_finished(&mut app, 10);
+    assert_eq!(event.state, ActionState::Success);
+    assert_eq!(
+        app.world().resource::<Log>().0,
+        ["patrol", "challenge", "alarm"]
+    );
+}
+
+#[test]
+fn undeclared_state() {
+    let mut app = machine_app(StateMachine::build("patrol").state("idle", Step("idle", None)));
+    assert_eq!(
+        common::next_finished(&mut app, 10).state,
+        ActionState::Failure
+    );
+
+    let mut app = machine_app(
+        StateMachine::build("patrol")
+            .state("patrol", Step("patrol", Some(true)))
+            .on_success("patrol", "alarm"),
+    );
+    assert_eq!(
+        common::next_finished(&mut app, 10).state,
+        ActionState::Failure
+    );
+    assert!(app.world().resource::<Log>().0.is_empty());
+
+    let mut app = machine_app(
+        StateMachine::build("patrol")
+            .state("patrol", Step("patrol", Some(true)))
+            .on_failure("alarm", "patrol"),
+    );
+    assert_eq!(
+        common::next_finished(&mut app, 10).state,
+        ActionState::Failure
+    );
+    assert!(app.world().resource::<Log>().0.is_empty());
+}
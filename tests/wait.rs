@@ -0,0 +1,87 @@
+mod common;
+
+use bevy::prelude::*;
+use big_brain::*;
+use std::time::Duration;
+
+#[derive(Default, Resource)]
+struct Finished(Option<u32>);
+
+#[derive(Component, Clone, ActionSpawn)]
+struct Done;
+
+fn done_action(
+    mut finished: ResMut<Finished>,
+    frame: Res<bevy::core::FrameCount>,
+    mut query: Query<ActionQuery, With<Done>>,
+) {
+    for mut action in query.iter_mut() {
+        if action.is_executing() {
+            finished.0.get_or_insert(frame.0);
+            action.success();
+        }
+    }
+}
+
+fn app(steps: SequenceSpawner) -> App {
+    let mut app = common::timed_app(Duration::from_millis(100));
+    app.init_resource::<Finished>()
+        .add_systems(Update, done_action.in_set(BigBrainSet::Actions));
+
+    common::spawn_thinker(
+        &mut app,
+        ThinkerSpawner::highest(0.0).when(FixedScorer(1.0), steps),
+    );
+    app
+}
+
+#[test]
+fn wait() {
+    let mut app = app(Sequence::step((Wait::secs(1.0), Done)));
+    common::update(&mut app, 30);
+
+    // Ten frames of waiting, plus a few frames to spawn the actions.
+    let frame = app.world().resource::<Finished>().0.unwrap();
+    assert!((10..15).contains(&frame), "finished on frame {frame}");
+}
+
+#[test]
+fn progress() {
+    let mut app = app(Sequence::step((Wait::secs(0.5), Wait::secs(0.5), Done)));
+    let mut reported = Vec::new();
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&ActionProgress, With<Sequence>>();
+    common::update_until(&mut app, 30, |app| {
+        reported.extend(query.iter(app.world()).map(ActionProgress::get));
+        app.world().resource::<Finished>().0
+    });
+
+    assert!(reported.windows(2).all(|w| w[0] <= w[1]), "{reported:?}");
+    assert!(
+        reported.iter().any(|&p| (0.3..0.4).contains(&p)),
+        "{reported:?}"
+    );
+}
+
+fn finished_frame(wait: Wait) -> Option<u32> {
+    let mut app = app(Sequence::step((wait, Done)));
+    common::update(&mut app, 40);
+    app.world().resource::<Finished>().0
+}
+
+#[test]
+fn seeded() {
+    let wait = || Wait::between(0.5, 2.5).with_seed(7);
+    let frame = finished_frame(wait());
+    assert!(frame.is_some());
+    assert_eq!(frame, finished_frame(wait()));
+}
+
+#[test]
+fn invalid_durations() {
+    assert!(finished_frame(Wait::secs(-1.0)).is_some());
+    assert!(finished_frame(Wait::secs(f32::NAN)).is_some());
+    assert!(finished_frame(Wait::between(-2.0, f32::NAN)).is_some());
+}